@@ -40,13 +40,21 @@ const generateCommand = command({
         if (binding.variety === AthalarJsBindingType.ClassValidator) {
           logText(`Processing binding for`, binding.output);
           const allImports = uniq(
-            bindings.flatMap((b) => b.atoms.flatMap((a) => a.validators))
+            bindings
+              .filter((b) => b.variety === AthalarJsBindingType.ClassValidator)
+              .flatMap((b) => b.atoms.flatMap((a) => a.validators))
           );
           await addClassValidatorBindingsToProject(
             sourceFile,
             binding,
             allImports
           );
+        } else if (
+          binding.variety === AthalarJsBindingType.Zod &&
+          binding.contents
+        ) {
+          logText(`Processing binding for`, binding.output);
+          sourceFile.replaceWithText(binding.contents);
         }
       }
       for (const file of project.getSourceFiles()) {
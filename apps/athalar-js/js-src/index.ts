@@ -1,7 +1,7 @@
 import chalk from 'chalk';
 import { SourceFile, VariableDeclarationKind } from 'ts-morph';
 
-import { AthalarJsBinding } from '..';
+import { AthalarJsBinding, ClassValidatorProfile } from '..';
 
 export const BINARY = 'athalar'
 
@@ -21,6 +21,7 @@ export const addClassValidatorBindingsToProject = async (
   allImports: string[]
 ) => {
   const importSpecifier = 'class-validator';
+  const details = binding.details as ClassValidatorProfile;
   sourceFile.addVariableStatement({
     declarationKind: VariableDeclarationKind.Const,
     declarations: [
@@ -40,7 +41,7 @@ export const addClassValidatorBindingsToProject = async (
   });
   // create class
   const sourceClass = sourceFile.addClass({
-    name: binding.details.className,
+    name: details.className,
     isExported: true,
     docs: [
       `This class contains all the properties decorated with decorators from '${importSpecifier}'`,
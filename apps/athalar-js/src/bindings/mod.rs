@@ -1,22 +1,15 @@
-use athalar_core::AthalarAdapter;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
 mod class_validator;
+mod zod;
 
 pub use class_validator::{ClassValidator, ClassValidatorProfile};
+pub use zod::ZodProfile;
 
 #[derive(Debug)]
 #[napi]
 pub enum AthalarJsBindingType {
     ClassValidator,
-}
-
-impl From<AthalarAdapter> for AthalarJsBindingType {
-    fn from(aa: AthalarAdapter) -> Self {
-        match aa {
-            AthalarAdapter::ClassValidator(_) => Self::ClassValidator,
-            AthalarAdapter::Pydantic(_) => unimplemented!(),
-        }
-    }
+    Zod,
 }
@@ -0,0 +1,9 @@
+use napi_derive::napi;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct ZodProfile {
+    pub schema_name: String,
+
+    pub type_name: String,
+}
@@ -1 +1,3 @@
 pub const DEFAULT_CLASS_NAME: &str = "ApplicationConfig";
+pub const DEFAULT_SCHEMA_NAME: &str = "ConfigSchema";
+pub const DEFAULT_TYPE_NAME: &str = "Config";
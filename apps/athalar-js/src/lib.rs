@@ -2,13 +2,18 @@ mod atoms;
 mod bindings;
 mod constants;
 
-use athalar_core::{from_path, Athalar, AthalarAdapter};
+use athalar_core::{
+    from_path, AdapterRegistry, Athalar, AthalarAdapter, AthalarAdapterKind, RenderContext,
+    TypedValue,
+};
+use athalar_zod::ZodRenderer;
 use atoms::AthalarJsKind;
-use bindings::{AthalarJsBindingType, ClassValidator, ClassValidatorProfile};
-use constants::DEFAULT_CLASS_NAME;
+use bindings::{AthalarJsBindingType, ClassValidator, ClassValidatorProfile, ZodProfile};
+use constants::{DEFAULT_CLASS_NAME, DEFAULT_SCHEMA_NAME, DEFAULT_TYPE_NAME};
 use itertools::{EitherOrBoth, Itertools};
-use napi::{Error, Result, Status};
+use napi::{bindgen_prelude::Either, Error, Result, Status};
 use napi_derive::napi;
+use std::collections::HashMap;
 
 #[derive(Debug)]
 #[napi(object)]
@@ -25,11 +30,65 @@ pub struct AthalarJsValidationReport {
     pub generators: Vec<AthalarJsReport>,
 
     pub partials: Vec<AthalarJsReport>,
+
+    pub values: Vec<AthalarJsReport>,
+}
+
+#[napi(object)]
+pub struct AthalarJsValidatedValues {
+    /// The values that passed validation, converted to the kind of their variable
+    pub values: HashMap<String, Either<f64, String>>,
+
+    /// The values that are missing or invalid, and the ones set using a deprecated name
+    pub reports: Vec<AthalarJsReport>,
 }
 
 #[napi]
 pub struct AthalarJs(Athalar);
 
+/// The renderers of the bindings that are generated entirely by Athalar. Class validator
+/// bindings are generated by the javascript side, using their atoms.
+fn registry() -> AdapterRegistry {
+    let mut registry = AdapterRegistry::default();
+    registry.register(AthalarAdapterKind::Zod, ZodRenderer);
+    registry
+}
+
+/// The variety and the settings of a binding, if its profile is supported by this package
+fn get_details(
+    profile: &AthalarAdapter,
+) -> Option<(
+    AthalarJsBindingType,
+    Either<ClassValidatorProfile, ZodProfile>,
+)> {
+    match profile {
+        AthalarAdapter::ClassValidator(x) => Some((
+            AthalarJsBindingType::ClassValidator,
+            Either::A(ClassValidatorProfile {
+                class_name: x
+                    .class_name
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_CLASS_NAME.to_string()),
+            }),
+        )),
+        AthalarAdapter::Zod(x) => Some((
+            AthalarJsBindingType::Zod,
+            Either::B(ZodProfile {
+                schema_name: x
+                    .schema_name
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_SCHEMA_NAME.to_string()),
+                type_name: x
+                    .type_name
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_TYPE_NAME.to_string()),
+            }),
+        )),
+        // the other profiles are generated by the CLI
+        _ => None,
+    }
+}
+
 #[napi(object)]
 pub struct AthalarJsBindingInformation {
     /// The fully qualified path to where the binding needs to be generated
@@ -79,31 +138,91 @@ impl AthalarJs {
                 message: msg.1.message.clone(),
             });
         }
+        let values = report
+            .values
+            .config
+            .iter()
+            .map(|msg| AthalarJsReport {
+                level: msg.1.level.to_string(),
+                message: msg.1.message.clone(),
+            })
+            .collect();
         AthalarJsValidationReport {
             generators,
             partials,
+            values,
         }
     }
 
+    /// Check the values (usually `process.env`) against the variables of a generator
+    /// # Arguments
+    /// * generator: The name of the generator
+    /// * values: The values keyed by the name of the variable
+    #[napi]
+    pub fn validate_values(
+        &self,
+        generator: String,
+        values: HashMap<String, String>,
+    ) -> Result<AthalarJsValidatedValues> {
+        let validated = self
+            .0
+            .validate_values(&generator, &values)
+            .map_err(|e| Error::new(Status::GenericFailure, e))?;
+        Ok(AthalarJsValidatedValues {
+            values: validated
+                .values
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        TypedValue::Number(n) => Either::A(n),
+                        TypedValue::String(s) => Either::B(s),
+                    };
+                    (name, value)
+                })
+                .collect(),
+            reports: validated
+                .reports
+                .into_iter()
+                .map(|r| AthalarJsReport {
+                    level: r.level.to_string(),
+                    message: r.message,
+                })
+                .collect(),
+        })
+    }
+
     /// Get the final information that will be used to generate the bindings
     #[napi]
     pub fn get_information(&self) -> Result<Vec<AthalarJsBinding>> {
         let mut bindings = vec![];
-        let information = self.0.get_information().unwrap();
+        let registry = registry();
+        let information = self
+            .0
+            .get_information()
+            .map_err(|e| Error::new(Status::GenericFailure, e))?;
+        let project_source = information.config.project_source();
         for (generator, atoms) in information.generators.iter() {
+            let context = RenderContext {
+                generator_name: &generator.name,
+                project_source: &project_source,
+            };
             for binding in generator.data.bindings.iter() {
-                let details = match &binding.profile {
-                    AthalarAdapter::ClassValidator(x) => ClassValidatorProfile {
-                        class_name: x
-                            .class_name
-                            .clone()
-                            .unwrap_or_else(|| DEFAULT_CLASS_NAME.to_string()),
-                    },
-                    AthalarAdapter::Pydantic(_) => continue,
+                let (variety, details) = match get_details(&binding.profile) {
+                    Some(details) => details,
+                    None => continue,
+                };
+                let contents = match registry.get(AthalarAdapterKind::from(&binding.profile)) {
+                    Some(renderer) => renderer
+                        .render(binding, atoms, &context)
+                        .map_err(|e| Error::new(Status::GenericFailure, e.to_string()))?
+                        .into_iter()
+                        .next()
+                        .map(|file| file.contents),
+                    None => None,
                 };
                 // the final path where the output of this binding must be placed
                 let output = binding
-                    .output(&information.config.project_source())
+                    .output(&project_source)
                     .into_os_string()
                     .into_string()
                     .unwrap();
@@ -125,11 +244,11 @@ impl AthalarJs {
                     };
                     _atoms.push(_atom);
                 }
-                let variety = AthalarJsBindingType::from(binding.profile.clone());
                 let _binding = AthalarJsBinding {
                     output,
                     atoms: _atoms,
                     details,
+                    contents,
                     variety,
                 };
                 bindings.push(_binding);
@@ -158,7 +277,11 @@ pub struct AthalarJsBinding {
 
     pub variety: AthalarJsBindingType,
 
-    pub details: ClassValidatorProfile,
+    /// The settings of the binding, depending on its variety
+    pub details: Either<ClassValidatorProfile, ZodProfile>,
+
+    /// The rendered output, for bindings that are generated entirely by Athalar
+    pub contents: Option<String>,
 
     pub atoms: Vec<AthalarJsAtom>,
 }
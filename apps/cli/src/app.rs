@@ -1,3 +1,4 @@
+use crate::init::InitAdapter;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -9,6 +10,152 @@ pub enum Commands {
     Generate {
         #[arg(help = "The path where the project is present, defaults to $PWD")]
         path: Option<PathBuf>,
+
+        #[arg(
+            long,
+            help = "Fail with the differences if the generated files are out of date, without writing them"
+        )]
+        check: bool,
+
+        #[arg(
+            long,
+            conflicts_with_all = ["check", "stdout"],
+            help = "List the files that would be created or changed, without writing them"
+        )]
+        dry_run: bool,
+
+        #[arg(
+            long,
+            conflicts_with = "check",
+            help = "Print the generated files instead of writing them"
+        )]
+        stdout: bool,
+
+        #[arg(
+            long,
+            conflicts_with_all = ["check", "dry_run", "stdout"],
+            help = "Regenerate the bindings whenever the project files change"
+        )]
+        watch: bool,
+    },
+
+    #[command(name = "init", about = "Create a new project")]
+    Init {
+        #[arg(help = "The path where the project should be created, defaults to $PWD")]
+        path: Option<PathBuf>,
+
+        #[arg(
+            long = "adapter",
+            value_enum,
+            help = "An adapter to generate a binding for, can be repeated (defaults to dotenv)"
+        )]
+        adapters: Vec<InitAdapter>,
+
+        #[arg(
+            long = "output",
+            help = "The output of the binding for the adapter at the same position, relative to the project"
+        )]
+        outputs: Vec<PathBuf>,
+    },
+
+    #[command(
+        name = "import",
+        about = "Import existing configuration into a project"
+    )]
+    Import {
+        #[command(subcommand)]
+        source: ImportSource,
+    },
+
+    #[command(name = "env", about = "Inspect the environment of an application")]
+    Env {
+        #[command(subcommand)]
+        command: EnvCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EnvCommand {
+    #[command(
+        name = "check",
+        about = "Check the environment against the configuration of a generator"
+    )]
+    Check {
+        #[arg(help = "The name of the generator to check against")]
+        generator: String,
+
+        #[arg(
+            long,
+            help = "Read the values from a dotenv file instead of the environment"
+        )]
+        env_file: Option<PathBuf>,
+
+        #[arg(
+            long,
+            help = "Report the variables with this prefix that are not declared"
+        )]
+        prefix: Option<String>,
+
+        #[arg(long, help = "The path where the project is present, defaults to $PWD")]
+        project: Option<PathBuf>,
+    },
+
+    #[command(
+        name = "render",
+        about = "Render the values of an environment as a dotenv file"
+    )]
+    Render {
+        #[arg(help = "The name of the environment, eg: staging")]
+        environment: String,
+
+        #[arg(long, help = "The generator whose variables should be rendered")]
+        generator: String,
+
+        #[arg(long, help = "Write the dotenv file here instead of printing it")]
+        output: Option<PathBuf>,
+
+        #[arg(long, help = "The path where the project is present, defaults to $PWD")]
+        project: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ImportSource {
+    #[command(
+        name = "dotenv",
+        about = "Create a partial from a dotenv file",
+        long_about = "Create a partial from a dotenv file. There is no boolean kind yet, so \
+                      booleans are imported as strings"
+    )]
+    Dotenv {
+        #[arg(help = "The dotenv file to import")]
+        file: PathBuf,
+
+        #[arg(long, help = "The name of the partial to create")]
+        partial: String,
+
+        #[arg(long, help = "The path where the project is present, defaults to $PWD")]
+        project: Option<PathBuf>,
+    },
+
+    #[command(
+        name = "pydantic",
+        about = "Create a partial from a pydantic settings class",
+        long_about = "Create a partial from a pydantic settings class. There is no boolean \
+                      kind yet, so booleans are imported as strings"
+    )]
+    Pydantic {
+        #[arg(help = "The python file where the class is declared")]
+        file: PathBuf,
+
+        #[arg(long = "class", help = "The name of the class to import")]
+        class_name: String,
+
+        #[arg(long, help = "The name of the partial to create")]
+        partial: String,
+
+        #[arg(long, help = "The path where the project is present, defaults to $PWD")]
+        project: Option<PathBuf>,
     },
 }
 
@@ -0,0 +1,176 @@
+use crate::import::parse_dotenv;
+use anyhow::anyhow;
+use athalar_core::{
+    from_path,
+    reporting::{data::EnvironmentReportCreator, ReportLevel, ReportMessage},
+};
+use athalar_dotenv::quote_value;
+use std::{collections::HashMap, fs, path::Path};
+
+/// Checks the values (usually the environment of the process) against the atoms of a
+/// generator. Variables that start with the prefix but are not declared are reported as
+/// unknown.
+pub fn check_environment(
+    project: &Path,
+    generator_name: &str,
+    values: &HashMap<String, String>,
+    prefix: Option<&str>,
+) -> anyhow::Result<Vec<ReportMessage>> {
+    let athalar = from_path(project.to_string_lossy().to_string()).map_err(|e| anyhow!(e))?;
+    let mut reports = athalar
+        .validate_values(generator_name, values)
+        .map_err(|e| anyhow!(e))?
+        .reports;
+    if let Some(prefix) = prefix {
+        let information = athalar.get_information().map_err(|e| anyhow!(e))?;
+        let (_, atoms) = information
+            .generators
+            .iter()
+            .find(|(generator, _)| generator.name == generator_name)
+            .unwrap();
+        let mut unknown = values
+            .keys()
+            .filter(|name| name.starts_with(prefix))
+            .filter(|name| {
+                !atoms
+                    .iter()
+                    .any(|a| &a.name == *name || a.deprecated_names.contains(name))
+            })
+            .collect::<Vec<_>>();
+        unknown.sort();
+        for name in unknown {
+            reports.push(EnvironmentReportCreator::unknown_variable(name, prefix));
+        }
+    }
+    Ok(reports)
+}
+
+/// Renders the values of an environment as a dotenv file, with the variables of the
+/// generator in the order they are declared. The values that are assigned with `!FromEnv`
+/// are read from `env`. Fails (after printing the reports) if the resolved values do not
+/// pass validation.
+pub fn render_values(
+    project: &Path,
+    environment: &str,
+    generator_name: &str,
+    env: &HashMap<String, String>,
+) -> anyhow::Result<String> {
+    let athalar = from_path(project.to_string_lossy().to_string()).map_err(|e| anyhow!(e))?;
+    let values = athalar
+        .resolve_values(environment, env)
+        .map_err(|e| anyhow!(e))?;
+    let reports = athalar
+        .validate_values(generator_name, &values)
+        .map_err(|e| anyhow!(e))?
+        .reports;
+    for report in reports.iter() {
+        eprintln!("{}: {}", report.level, report.message);
+    }
+    if reports.iter().any(|r| r.level == ReportLevel::Severe) {
+        return Err(anyhow!(
+            "The {:?} values are not valid for the generator {:?}",
+            environment,
+            generator_name
+        ));
+    }
+    let information = athalar.get_information().map_err(|e| anyhow!(e))?;
+    let (_, atoms) = information
+        .generators
+        .iter()
+        .find(|(generator, _)| generator.name == generator_name)
+        .unwrap();
+    let mut contents = String::new();
+    for atom in atoms {
+        if let Some(value) = values.get(&atom.name) {
+            contents.push_str(&format!("{}={}\n", atom.name, quote_value(value)));
+        }
+    }
+    Ok(contents)
+}
+
+/// Reads the values from a dotenv file, or from the environment of the process when no
+/// file is given.
+pub fn get_values(env_file: Option<&Path>) -> anyhow::Result<HashMap<String, String>> {
+    match env_file {
+        Some(file) => {
+            let contents =
+                fs::read_to_string(file).map_err(|_| anyhow!("Unable to read file {:?}", file))?;
+            Ok(parse_dotenv(&contents)?
+                .into_iter()
+                .map(|entry| (entry.name, entry.value))
+                .collect())
+        }
+        None => Ok(std::env::vars().collect()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::init::init;
+    use std::{env, path::PathBuf};
+
+    fn project(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(name);
+        fs::remove_dir_all(&directory).ok();
+        init(&directory, &[], &[]).unwrap();
+        directory
+    }
+
+    fn get_codes(reports: &[ReportMessage]) -> Vec<&'static str> {
+        reports.iter().map(|r| r.code).collect()
+    }
+
+    #[test]
+    fn environment_is_checked_against_the_generator() {
+        let project = project("athalar_cli_env_check_test");
+        let values = HashMap::from([
+            ("DATABASE_URL".to_string(), "localhost".to_string()),
+            ("APP_WORKERS".to_string(), "4".to_string()),
+        ]);
+        let reports = check_environment(&project, "app", &values, Some("APP_")).unwrap();
+        assert_eq!(get_codes(&reports), vec!["InvalidValue", "UnknownVariable"]);
+        let reports = check_environment(&project, "app", &HashMap::new(), None).unwrap();
+        assert_eq!(get_codes(&reports), vec!["MissingValue"]);
+        assert!(check_environment(&project, "missing", &values, None).is_err());
+    }
+
+    #[test]
+    fn values_are_rendered_for_an_environment() {
+        let project = project("athalar_cli_env_render_test");
+        let values = project.join("src/values");
+        fs::create_dir_all(&values).unwrap();
+        fs::write(
+            values.join("staging.ath.yaml"),
+            "values:\n  APP_PORT: 8080\n  DATABASE_URL: !FromEnv STAGING_DATABASE_URL\n",
+        )
+        .unwrap();
+        let env = HashMap::from([(
+            "STAGING_DATABASE_URL".to_string(),
+            "postgres://db:5432/app".to_string(),
+        )]);
+        assert_eq!(
+            render_values(&project, "staging", "app", &env).unwrap(),
+            "APP_PORT=8080\nDATABASE_URL=postgres://db:5432/app\n"
+        );
+        assert!(render_values(&project, "staging", "app", &HashMap::new()).is_err());
+        assert!(render_values(&project, "production", "app", &env).is_err());
+    }
+
+    #[test]
+    fn deprecated_names_provide_the_value() {
+        let project = project("athalar_cli_env_deprecated_test");
+        let partial = project.join("src/partials/app.ath.yaml");
+        let contents = fs::read_to_string(&partial).unwrap().replace(
+            "    secret: true\n",
+            "    secret: true\n    deprecated_names:\n      - DB_URL\n",
+        );
+        fs::write(&partial, contents).unwrap();
+        let values = HashMap::from([(
+            "DB_URL".to_string(),
+            "postgres://localhost:5432".to_string(),
+        )]);
+        let reports = check_environment(&project, "app", &values, Some("DB_")).unwrap();
+        assert_eq!(get_codes(&reports), vec!["DeprecatedName"]);
+    }
+}
@@ -0,0 +1,214 @@
+use super::{is_secret, write_partial, ImportedAtom};
+use anyhow::anyhow;
+use athalar_core::AtomValidator;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A variable that was read from a dotenv file.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct DotenvEntry {
+    pub(crate) name: String,
+    pub(crate) value: String,
+    pub(crate) description: Option<String>,
+}
+
+// reads the escapes of a double quoted value from left to right, so that an escaped
+// backslash is never taken as the start of another escape (eg: `C:\\new`)
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some(c @ ('"' | '\\' | '$')) => unescaped.push(c),
+            // escapes that are not known are kept as they are
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+// removes the quotes around a value and the comment after it
+fn get_value(raw: &str) -> String {
+    let raw = raw.trim();
+    if let Some(quoted) = raw.strip_prefix('"') {
+        // the value ends at the first quote that is not escaped
+        let mut escaped = false;
+        let end = quoted.char_indices().find_map(|(index, c)| {
+            let closing = c == '"' && !escaped;
+            escaped = c == '\\' && !escaped;
+            closing.then_some(index)
+        });
+        if let Some(end) = end {
+            return unescape(&quoted[..end]);
+        }
+    }
+    if let Some(quoted) = raw.strip_prefix('\'') {
+        if let Some(end) = quoted.find('\'') {
+            return quoted[..end].to_string();
+        }
+    }
+    match raw.find(" #") {
+        Some(index) => raw[..index].trim_end().to_string(),
+        None => raw.to_string(),
+    }
+}
+
+/// Reads the variables in a dotenv file. The comments directly above a variable become
+/// its description. A variable that is assigned more than once keeps its first position
+/// and its last value, like it would when the file is sourced.
+pub(crate) fn parse_dotenv(contents: &str) -> anyhow::Result<Vec<DotenvEntry>> {
+    let mut entries: Vec<DotenvEntry> = vec![];
+    let mut comments = vec![];
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            comments.clear();
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if !comment.trim().is_empty() {
+                comments.push(comment.trim().to_string());
+            }
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (name, value) = line
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Line {} is not a variable assignment", number + 1))?;
+        let name = name.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+        {
+            return Err(anyhow!(
+                "Line {} has an invalid name {:?}",
+                number + 1,
+                name
+            ));
+        }
+        let description = match comments.is_empty() {
+            true => None,
+            false => Some(comments.join(" ")),
+        };
+        comments.clear();
+        let entry = DotenvEntry {
+            name: name.to_string(),
+            value: get_value(value),
+            description,
+        };
+        match entries.iter_mut().find(|e| e.name == entry.name) {
+            Some(existing) => existing.value = entry.value,
+            None => entries.push(entry),
+        }
+    }
+    Ok(entries)
+}
+
+/// The validator that best describes the value of a variable. There is no boolean kind
+/// yet, so booleans are imported as strings.
+fn get_validator(name: &str, value: &str) -> &'static str {
+    if name.to_ascii_uppercase().ends_with("PORT") && AtomValidator::Port.is_valid(value) {
+        return "Port";
+    }
+    if AtomValidator::Number.is_valid(value) {
+        return "Number";
+    }
+    if AtomValidator::Url.is_valid(value) {
+        return "Url";
+    }
+    "String"
+}
+
+/// Writes the variables of a dotenv file as a new partial in the project and returns the
+/// path of the partial.
+pub fn import_dotenv(project: &Path, file: &Path, partial: &str) -> anyhow::Result<PathBuf> {
+    let contents =
+        fs::read_to_string(file).map_err(|_| anyhow!("Unable to read file {:?}", file))?;
+    let entries = parse_dotenv(&contents)?;
+    if entries.is_empty() {
+        return Err(anyhow!("No variables were found in {:?}", file));
+    }
+    let atoms = entries
+        .into_iter()
+        .map(|entry| ImportedAtom {
+            validator: get_validator(&entry.name, &entry.value),
+            secret: is_secret(&entry.name),
+            default: match entry.value.is_empty() {
+                true => None,
+                false => Some(entry.value),
+            },
+            name: entry.name,
+            description: entry.description,
+            optional: false,
+        })
+        .collect::<Vec<_>>();
+    write_partial(project, partial, &atoms)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn comments_above_variables_become_descriptions() {
+        let entries = parse_dotenv(
+            "# The mail server\n# used for alerts\nMAIL_HOST=smtp.example.com # inline\n\n# unrelated\n\nexport DEBUG='on'\nMAIL_HOST=\"mail.example.com\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                DotenvEntry {
+                    name: "MAIL_HOST".to_string(),
+                    value: "mail.example.com".to_string(),
+                    description: Some("The mail server used for alerts".to_string()),
+                },
+                DotenvEntry {
+                    name: "DEBUG".to_string(),
+                    value: "on".to_string(),
+                    description: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn comments_after_quoted_values_are_removed() {
+        assert_eq!(get_value("\"x\" # note"), "x");
+        assert_eq!(get_value("'x' # note"), "x");
+        assert_eq!(get_value("\"a # b\\\" c\" # note"), "a # b\" c");
+        assert_eq!(get_value("x # note"), "x");
+        assert_eq!(get_value("\"C:\\\\new\" # note"), "C:\\new");
+        assert_eq!(get_value("\"a\\nb \\$HOME\""), "a\nb $HOME");
+    }
+
+    #[test]
+    fn validators_are_inferred_from_values() {
+        assert_eq!(get_validator("MAIL_PORT", "25"), "Port");
+        assert_eq!(get_validator("WORKERS", "4"), "Number");
+        assert_eq!(get_validator("RATIO", "0.5"), "Number");
+        assert_eq!(
+            get_validator("DATABASE_URL", "postgres://db:5432/app"),
+            "Url"
+        );
+        assert_eq!(get_validator("DEBUG", "true"), "String");
+        assert_eq!(get_validator("NAME", ""), "String");
+    }
+
+    #[test]
+    fn malformed_lines_are_errors() {
+        assert!(parse_dotenv("MAIL_HOST\n").is_err());
+    }
+}
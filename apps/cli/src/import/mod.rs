@@ -0,0 +1,143 @@
+//! Creates partials from configuration that was written before the project used athalar.
+
+mod dotenv;
+mod pydantic;
+
+use anyhow::anyhow;
+use athalar_core::from_path;
+use serde_yaml::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub use dotenv::import_dotenv;
+pub(crate) use dotenv::parse_dotenv;
+pub use pydantic::import_pydantic;
+
+/// Suffixes of the names of variables that probably hold sensitive values
+const SECRET_SUFFIXES: &[&str] = &["_KEY", "_SECRET", "_TOKEN", "_PASSWORD"];
+
+/// An atom that was read from existing configuration.
+#[derive(Debug, PartialEq, Eq)]
+struct ImportedAtom {
+    name: String,
+    description: Option<String>,
+    default: Option<String>,
+    validator: &'static str,
+    optional: bool,
+    secret: bool,
+}
+
+fn is_secret(name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    SECRET_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+// plain yaml scalars are written as they are, everything else is quoted. Values that span
+// several lines are double quoted, since a block scalar would have to be indented under the
+// key that it is written after
+fn get_yaml_string(value: &str) -> anyhow::Result<String> {
+    if value.contains(['\n', '\r']) {
+        let escaped = value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+            .replace('\t', "\\t");
+        return Ok(format!("\"{}\"", escaped));
+    }
+    Ok(serde_yaml::to_string(&Value::String(value.to_string()))?
+        .trim_end()
+        .to_string())
+}
+
+fn get_partial_contents(atoms: &[ImportedAtom]) -> anyhow::Result<String> {
+    let mut contents = String::from("config:\n");
+    for atom in atoms {
+        contents.push_str(&format!("  - name: {}\n", get_yaml_string(&atom.name)?));
+        if let Some(description) = &atom.description {
+            contents.push_str(&format!(
+                "    description: {}\n",
+                get_yaml_string(description)?
+            ));
+        }
+        // the values of secrets must never end up in the partials
+        if let (Some(default), false) = (&atom.default, atom.secret) {
+            contents.push_str(&format!("    default: {}\n", get_yaml_string(default)?));
+        }
+        if atom.optional {
+            contents.push_str("    optional: true\n");
+        }
+        if atom.secret {
+            contents.push_str("    secret: true\n");
+        }
+        contents.push_str(&format!("    validators:\n      - !{}\n", atom.validator));
+    }
+    Ok(contents)
+}
+
+/// Writes the atoms as a new partial in the project and returns the path of the partial.
+/// Existing partials are never overwritten.
+fn write_partial(project: &Path, partial: &str, atoms: &[ImportedAtom]) -> anyhow::Result<PathBuf> {
+    let athalar = from_path(project.to_string_lossy().to_string()).map_err(|e| anyhow!(e))?;
+    let output = athalar
+        .config()
+        .partials()
+        .join(format!("{}.ath.yaml", partial));
+    if output.exists() {
+        return Err(anyhow!("Refusing to overwrite the partial at {:?}", output));
+    }
+    fs::create_dir_all(output.parent().unwrap())?;
+    fs::write(&output, get_partial_contents(atoms)?)
+        .map_err(|_| anyhow!("Unable to write file {:?}", output))?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn secrets_are_written_without_their_values() {
+        let atoms = vec![
+            ImportedAtom {
+                name: "API_KEY".to_string(),
+                description: None,
+                default: Some("hunter2".to_string()),
+                validator: "String",
+                optional: false,
+                secret: true,
+            },
+            ImportedAtom {
+                name: "MAIL_PORT".to_string(),
+                description: Some("The port: 25 or 587".to_string()),
+                default: Some("25".to_string()),
+                validator: "Port",
+                optional: true,
+                secret: false,
+            },
+        ];
+        assert_eq!(
+            get_partial_contents(&atoms).unwrap(),
+            "config:\n  - name: API_KEY\n    secret: true\n    validators:\n      - !String\n  - name: MAIL_PORT\n    description: 'The port: 25 or 587'\n    default: '25'\n    optional: true\n    validators:\n      - !Port\n"
+        );
+    }
+
+    #[test]
+    fn values_on_several_lines_are_read_back() {
+        let atoms = vec![ImportedAtom {
+            name: "MOTD".to_string(),
+            description: Some("The message\nof the day".to_string()),
+            default: Some("hello\n\\\"world\"\t\r\n".to_string()),
+            validator: "String",
+            optional: false,
+            secret: false,
+        }];
+        let contents = get_partial_contents(&atoms).unwrap();
+        let partial = serde_yaml::from_str::<Value>(&contents).unwrap();
+        let atom = &partial["config"][0];
+        assert_eq!(atom["description"], "The message\nof the day");
+        assert_eq!(atom["default"], "hello\n\\\"world\"\t\r\n");
+    }
+}
@@ -0,0 +1,498 @@
+use super::{is_secret, write_partial, ImportedAtom};
+use anyhow::anyhow;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A field of a settings class, as it was written.
+#[derive(Debug, PartialEq, Eq)]
+struct PydanticField {
+    name: String,
+    annotation: String,
+    default: Option<String>,
+    comment: Option<String>,
+}
+
+fn get_indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+// splits at the separator, ignoring the ones inside brackets and quotes
+fn split_top_level(value: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![];
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quote = None;
+    for c in value.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, _) if c == separator && depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts
+}
+
+// the comment that starts after the code on a line, if any
+fn split_comment(line: &str) -> (&str, Option<&str>) {
+    let mut quote = None;
+    for (index, c) in line.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return (line[..index].trim_end(), Some(line[index + 1..].trim())),
+            _ => {}
+        }
+    }
+    (line, None)
+}
+
+// how many brackets are still open at the end of the code, ignoring the ones in strings
+fn get_open_brackets(code: &str) -> i32 {
+    let mut depth = 0;
+    let mut quote = None;
+    for c in code.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+// the `env_prefix` that is set by `model_config = SettingsConfigDict(..)`, or by the
+// `env_prefix = ..` of a nested `class Config`
+fn get_env_prefix(code: &str) -> Option<String> {
+    let arguments = match split_top_level(code, '=').as_slice() {
+        [key, value] => match key.split(':').next().unwrap().trim() {
+            "env_prefix" => return get_string_literal(value),
+            "model_config" => value.split_once('(')?.1.strip_suffix(')')?.to_string(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    split_top_level(&arguments, ',')
+        .iter()
+        .find_map(|argument| match split_top_level(argument, '=').as_slice() {
+            [key, value] if key == "env_prefix" => get_string_literal(value),
+            _ => None,
+        })
+}
+
+/// The fields of a settings class and the prefix of the environment variables that they
+/// are read from.
+#[derive(Debug, Default)]
+struct PydanticClass {
+    env_prefix: String,
+    fields: Vec<PydanticField>,
+}
+
+/// Reads the fields that are declared directly in the body of the class. The comments
+/// directly above a field (or after it on the same line) become its description.
+fn parse_class(contents: &str, class_name: &str) -> anyhow::Result<PydanticClass> {
+    let mut lines = contents.lines();
+    let class_indentation = loop {
+        let line = lines
+            .next()
+            .ok_or_else(|| anyhow!("Class {:?} was not found", class_name))?;
+        let declaration = line.trim_start();
+        if let Some(rest) = declaration.strip_prefix("class ") {
+            let name = rest.split(['(', ':']).next().unwrap().trim();
+            if name == class_name {
+                break get_indentation(line);
+            }
+        }
+    };
+    let mut class = PydanticClass::default();
+    let mut comments: Vec<String> = vec![];
+    let mut body_indentation = None;
+    // whether the lines belong to the nested `class Config` (of pydantic v1)
+    let mut in_config = false;
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            comments.clear();
+            continue;
+        }
+        let indentation = get_indentation(line);
+        if indentation <= class_indentation {
+            break;
+        }
+        let line = line.trim();
+        // docstrings are skipped along with everything that is written in them, which
+        // could otherwise look like fields (eg: `Attributes: ..`)
+        let unprefixed = line.trim_start_matches(['r', 'R']);
+        if let Some(delimiter) = ["\"\"\"", "'''"]
+            .into_iter()
+            .find(|d| unprefixed.starts_with(d))
+        {
+            if !unprefixed[delimiter.len()..].contains(delimiter) {
+                for line in lines.by_ref() {
+                    if line.contains(delimiter) {
+                        break;
+                    }
+                }
+            }
+            comments.clear();
+            continue;
+        }
+        // nested blocks (methods, inner classes etc) are skipped
+        let body_indentation = *body_indentation.get_or_insert(indentation);
+        if indentation != body_indentation {
+            if let Some(env_prefix) = get_env_prefix(split_comment(line).0).filter(|_| in_config) {
+                class.env_prefix = env_prefix;
+            }
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if !comment.trim().is_empty() {
+                comments.push(comment.trim().to_string());
+            }
+            continue;
+        }
+        let (code, comment) = split_comment(line);
+        let mut code = code.to_string();
+        // a statement goes on until its brackets are closed, eg: a `Field(..)` that spans
+        // several lines
+        while get_open_brackets(&code) > 0 {
+            match lines.next() {
+                Some(next) => {
+                    code.push(' ');
+                    code.push_str(split_comment(next.trim()).0);
+                }
+                None => break,
+            }
+        }
+        in_config = code.starts_with("class Config");
+        if let Some(env_prefix) = get_env_prefix(&code) {
+            class.env_prefix = env_prefix;
+        }
+        if let Some(comment) = comment.filter(|c| !c.is_empty()) {
+            comments.push(comment.to_string());
+        }
+        let description = match comments.is_empty() {
+            true => None,
+            false => Some(comments.join(" ")),
+        };
+        comments.clear();
+        let (name, rest) = match code.split_once(':') {
+            Some((name, rest)) => (name.trim(), rest),
+            None => continue,
+        };
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            || name.starts_with(|c: char| c.is_ascii_digit())
+            || name == "model_config"
+        {
+            continue;
+        }
+        let mut parts = split_top_level(rest, '=').into_iter();
+        let annotation = parts.next().unwrap();
+        let default = parts.collect::<Vec<_>>();
+        if annotation.starts_with("ClassVar") {
+            continue;
+        }
+        class.fields.push(PydanticField {
+            name: name.to_string(),
+            annotation,
+            default: match default.is_empty() {
+                true => None,
+                false => Some(default.join("=")),
+            },
+            comment: description,
+        });
+    }
+    Ok(class)
+}
+
+/// Removes `Optional[..]`, `Union[.., None]` and `.. | None` from an annotation and
+/// returns whether it was present.
+fn strip_optional(annotation: &str) -> (String, bool) {
+    if let Some(inner) = annotation
+        .strip_prefix("Optional[")
+        .and_then(|a| a.strip_suffix(']'))
+    {
+        return (inner.trim().to_string(), true);
+    }
+    let members = match annotation
+        .strip_prefix("Union[")
+        .and_then(|a| a.strip_suffix(']'))
+    {
+        Some(inner) => split_top_level(inner, ','),
+        None => split_top_level(annotation, '|'),
+    };
+    let without_none = members
+        .iter()
+        .filter(|m| m.as_str() != "None")
+        .cloned()
+        .collect::<Vec<_>>();
+    match without_none.len() == members.len() {
+        true => (annotation.to_string(), false),
+        false => (without_none.join(" | "), true),
+    }
+}
+
+/// The validator that corresponds to a type annotation. There is no boolean kind yet, so
+/// booleans are imported as strings, and types that are not known are imported as `Noop`.
+fn get_validator(annotation: &str) -> &'static str {
+    let annotation = annotation.trim();
+    let compact = annotation.replace(' ', "");
+    if compact.starts_with("conint(") && compact.contains("ge=0") && compact.contains("le=65535") {
+        return "Port";
+    }
+    let base = annotation
+        .split(['[', '('])
+        .next()
+        .unwrap()
+        .rsplit('.')
+        .next()
+        .unwrap();
+    match base {
+        "int" | "float" | "conint" | "confloat" | "PositiveInt" | "NonNegativeInt"
+        | "PositiveFloat" | "NonNegativeFloat" => "Number",
+        "str" | "constr" | "SecretStr" | "bool" => "String",
+        _ if base.ends_with("Url") || base.ends_with("Dsn") => "Url",
+        _ => "Noop",
+    }
+}
+
+// removes the quotes around a python string literal
+fn get_string_literal(value: &str) -> Option<String> {
+    for quote in ["\"", "'"] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return Some(inner.to_string());
+        }
+    }
+    None
+}
+
+/// The default of a field as it would appear in the environment, and its description when
+/// it is declared using `Field(..)`.
+fn get_default(default: &str) -> (Option<String>, Option<String>) {
+    let (value, description) = match default
+        .strip_prefix("Field(")
+        .and_then(|d| d.strip_suffix(')'))
+    {
+        Some(arguments) => {
+            let mut value = None;
+            let mut description = None;
+            for argument in split_top_level(arguments, ',') {
+                match split_top_level(&argument, '=').as_slice() {
+                    [positional] if !positional.is_empty() => value = Some(positional.clone()),
+                    [key, v] if key == "default" => value = Some(v.clone()),
+                    [key, v] if key == "description" => description = get_string_literal(v),
+                    _ => {}
+                }
+            }
+            (value, description)
+        }
+        None => (Some(default.to_string()), None),
+    };
+    let value = value.and_then(|v| match v.as_str() {
+        "None" | "..." => None,
+        "True" => Some("true".to_string()),
+        "False" => Some("false".to_string()),
+        _ => Some(get_string_literal(&v).unwrap_or(v)),
+    });
+    (value, description)
+}
+
+/// The atom for a field, named after the environment variable that pydantic reads it from.
+fn get_atom(field: PydanticField, env_prefix: &str) -> ImportedAtom {
+    let (annotation, optional) = strip_optional(&field.annotation);
+    let (default, description) = match &field.default {
+        Some(default) => get_default(default),
+        None => (None, None),
+    };
+    ImportedAtom {
+        validator: get_validator(&annotation),
+        secret: annotation.contains("SecretStr") || is_secret(&field.name),
+        description: description.or(field.comment),
+        default,
+        optional,
+        name: format!("{}{}", env_prefix, field.name),
+    }
+}
+
+/// Writes the fields of a pydantic class as a new partial in the project and returns the
+/// path of the partial.
+pub fn import_pydantic(
+    project: &Path,
+    file: &Path,
+    class_name: &str,
+    partial: &str,
+) -> anyhow::Result<PathBuf> {
+    let contents =
+        fs::read_to_string(file).map_err(|_| anyhow!("Unable to read file {:?}", file))?;
+    let class = parse_class(&contents, class_name)?;
+    if class.fields.is_empty() {
+        return Err(anyhow!(
+            "No fields were found in the class {:?}",
+            class_name
+        ));
+    }
+    let atoms = class
+        .fields
+        .into_iter()
+        .map(|field| get_atom(field, &class.env_prefix))
+        .collect::<Vec<_>>();
+    write_partial(project, partial, &atoms)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SETTINGS: &str = r#"
+from typing import Optional
+
+from pydantic import AnyUrl, Field, SecretStr, conint
+from pydantic_settings import BaseSettings, SettingsConfigDict
+
+class Other(BaseSettings):
+    IGNORED: str
+
+class Settings(BaseSettings):
+    model_config = SettingsConfigDict(env_prefix="APP_")
+
+    # The port at which the mail server is listening at
+    MAIL_PORT: conint(ge=0, le=65535) = 25
+    MAIL_HOST: str = "localhost"  # The hostname of the mail server
+    API_URL: Optional[AnyUrl] = None
+    API_TOKEN: SecretStr
+    WORKERS: int = Field(4, description="How many workers to start")
+    DEBUG: bool = False
+
+    def url(self) -> str:
+        value: str = "nested"
+        return value
+"#;
+
+    #[test]
+    fn fields_are_read_from_the_class_body() {
+        let fields = parse_class(SETTINGS, "Settings").unwrap().fields;
+        let names = fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "MAIL_PORT",
+                "MAIL_HOST",
+                "API_URL",
+                "API_TOKEN",
+                "WORKERS",
+                "DEBUG"
+            ]
+        );
+        assert_eq!(
+            fields[0],
+            PydanticField {
+                name: "MAIL_PORT".to_string(),
+                annotation: "conint(ge=0, le=65535)".to_string(),
+                default: Some("25".to_string()),
+                comment: Some("The port at which the mail server is listening at".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn annotations_are_mapped_to_atoms() {
+        let class = parse_class(SETTINGS, "Settings").unwrap();
+        let atoms = class
+            .fields
+            .into_iter()
+            .map(|field| get_atom(field, &class.env_prefix))
+            .collect::<Vec<_>>();
+        let summary = atoms
+            .iter()
+            .map(|a| (a.validator, a.default.as_deref(), a.optional, a.secret))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("Port", Some("25"), false, false),
+                ("String", Some("localhost"), false, false),
+                ("Url", None, true, false),
+                ("String", None, false, true),
+                ("Number", Some("4"), false, false),
+                ("String", Some("false"), false, false),
+            ]
+        );
+        assert_eq!(
+            atoms[1].description.as_deref(),
+            Some("The hostname of the mail server")
+        );
+        assert_eq!(
+            atoms[4].description.as_deref(),
+            Some("How many workers to start")
+        );
+    }
+
+    #[test]
+    fn docstrings_are_skipped() {
+        let fields = parse_class(
+            "class Settings(BaseSettings):\n    \"\"\"The settings.\n\n    Attributes: the fields\n    \"\"\"\n\n    '''Notes: more'''\n    MAIL_HOST: str\n",
+            "Settings",
+        )
+        .unwrap()
+        .fields;
+        let names = fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["MAIL_HOST"]);
+    }
+
+    #[test]
+    fn field_calls_can_span_several_lines() {
+        let fields = parse_class(
+            "class Settings(BaseSettings):\n    WORKERS: int = Field(\n        4,  # the default\n        description=\"How many workers to start\",\n    )\n    DEBUG: bool = False\n",
+            "Settings",
+        )
+        .unwrap()
+        .fields;
+        assert_eq!(fields.len(), 2);
+        let atom = get_atom(fields.into_iter().next().unwrap(), "");
+        assert_eq!(atom.default.as_deref(), Some("4"));
+        assert_eq!(
+            atom.description.as_deref(),
+            Some("How many workers to start")
+        );
+    }
+
+    #[test]
+    fn env_prefix_is_applied_to_the_names() {
+        let class = parse_class(SETTINGS, "Settings").unwrap();
+        assert_eq!(class.env_prefix, "APP_");
+        let class = parse_class(
+            "class Settings(BaseSettings):\n    MAIL_HOST: str\n\n    class Config:\n        env_prefix = 'MAIL_'\n",
+            "Settings",
+        )
+        .unwrap();
+        assert_eq!(class.env_prefix, "MAIL_");
+        let names = class
+            .fields
+            .into_iter()
+            .map(|field| get_atom(field, &class.env_prefix).name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["MAIL_MAIL_HOST"]);
+    }
+
+    #[test]
+    fn missing_class_is_an_error() {
+        assert!(parse_class(SETTINGS, "Config").is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use anyhow::anyhow;
+use athalar_core::constants::ATHALAR_CONFIG_FILE;
+use clap::ValueEnum;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CONFIG_CONTENTS: &str = r#"version = "1"
+"#;
+
+const PARTIAL_NAME: &str = "app";
+
+const PARTIAL_CONTENTS: &str = r#"config:
+  - name: APP_PORT
+    description: The port that the application listens on
+    default: 8000
+    validators:
+      - !Port
+  - name: DATABASE_URL
+    description: The URL of the database that the application connects to
+    secret: true
+    validators:
+      - !Url
+"#;
+
+/// The adapters that a new project can be initialized with. The ones that need settings
+/// to be useful (like templates and plugins) are left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum InitAdapter {
+    ClassValidator,
+    Zod,
+    Pydantic,
+    PydanticSettings,
+    Rust,
+    Go,
+    JsonSchema,
+    Dotenv,
+    Kubernetes,
+    Compose,
+    Markdown,
+}
+
+impl InitAdapter {
+    /// The tag of the profile in a generator
+    fn tag(&self) -> &'static str {
+        match self {
+            Self::ClassValidator => "ClassValidator",
+            Self::Zod => "Zod",
+            Self::Pydantic => "Pydantic",
+            Self::PydanticSettings => "PydanticSettings",
+            Self::Rust => "Rust",
+            Self::Go => "Go",
+            Self::JsonSchema => "JsonSchema",
+            Self::Dotenv => "Dotenv",
+            Self::Kubernetes => "Kubernetes",
+            Self::Compose => "Compose",
+            Self::Markdown => "Markdown",
+        }
+    }
+
+    /// Where the output is placed when it is not specified, relative to `athalar.toml`
+    fn default_output(&self) -> &'static str {
+        match self {
+            Self::ClassValidator => "generated/config.ts",
+            Self::Zod => "generated/schema.ts",
+            Self::Pydantic => "generated/config.py",
+            Self::PydanticSettings => "generated/settings.py",
+            Self::Rust => "generated/config.rs",
+            Self::Go => "generated/config.go",
+            Self::JsonSchema => "generated/config.schema.json",
+            Self::Dotenv => ".env.example",
+            Self::Kubernetes => "generated/config.yaml",
+            Self::Compose => "generated/compose.yaml",
+            Self::Markdown => "CONFIGURATION.md",
+        }
+    }
+}
+
+fn get_generator_contents(bindings: &[(InitAdapter, PathBuf)]) -> String {
+    let mut contents = String::from("bindings:\n");
+    for (adapter, output) in bindings {
+        contents.push_str(&format!(
+            "  - output: {}\n    profile: !{} {{}}\n",
+            output.to_string_lossy(),
+            adapter.tag()
+        ));
+    }
+    contents.push_str(&format!(
+        "\nconfig:\n  - !IncludePartial {}\n",
+        PARTIAL_NAME
+    ));
+    contents
+}
+
+/// Creates a new project at the path, with a sample partial and a generator that has a
+/// binding for each of the adapters. The outputs are matched with the adapters in order,
+/// the adapters without one use their default output. Nothing is written if any of the
+/// project files already exist.
+pub fn init(path: &Path, adapters: &[InitAdapter], outputs: &[PathBuf]) -> anyhow::Result<()> {
+    let adapters = match adapters.is_empty() {
+        true => vec![InitAdapter::Dotenv],
+        false => adapters.to_vec(),
+    };
+    if outputs.len() > adapters.len() {
+        return Err(anyhow!(
+            "Received {} outputs for {} adapters",
+            outputs.len(),
+            adapters.len()
+        ));
+    }
+    let bindings = adapters
+        .iter()
+        .enumerate()
+        .map(|(index, adapter)| {
+            let output = outputs
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| PathBuf::from(adapter.default_output()));
+            (*adapter, output)
+        })
+        .collect::<Vec<_>>();
+    let file_name = format!("{}.ath.yaml", PARTIAL_NAME);
+    let files = [
+        (path.join(ATHALAR_CONFIG_FILE), CONFIG_CONTENTS.to_string()),
+        (
+            path.join("src").join("partials").join(&file_name),
+            PARTIAL_CONTENTS.to_string(),
+        ),
+        (
+            path.join("src").join("generators").join(&file_name),
+            get_generator_contents(&bindings),
+        ),
+    ];
+    if let Some((existing, _)) = files.iter().find(|(file, _)| file.exists()) {
+        return Err(anyhow!(
+            "Refusing to overwrite {:?}, an athalar project already exists",
+            existing
+        ));
+    }
+    for (file, contents) in files.iter() {
+        fs::create_dir_all(file.parent().unwrap())?;
+        fs::write(file, contents).map_err(|_| anyhow!("Unable to write file {:?}", file))?;
+    }
+    // the outputs must be creatable for the project to pass validation
+    for (_, output) in bindings.iter() {
+        if let Some(parent) = path.join(output).parent() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::render;
+    use std::env;
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(name);
+        fs::remove_dir_all(&directory).ok();
+        directory
+    }
+
+    #[test]
+    fn initialized_project_can_be_generated() {
+        let directory = directory("athalar_cli_init_test");
+        init(
+            &directory,
+            &[InitAdapter::Pydantic, InitAdapter::Dotenv],
+            &[PathBuf::from("app/config.py")],
+        )
+        .unwrap();
+        let final_files = render(directory.clone()).unwrap();
+        let paths = final_files.into_iter().map(|f| f.path).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                directory.join("app/config.py"),
+                directory.join(".env.example")
+            ]
+        );
+    }
+
+    #[test]
+    fn existing_project_is_not_overwritten() {
+        let directory = directory("athalar_cli_init_existing_test");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join(ATHALAR_CONFIG_FILE), "version = \"1\"\n").unwrap();
+        assert!(init(&directory, &[], &[]).is_err());
+        assert!(!directory.join("src").exists());
+    }
+}
@@ -1,27 +1,154 @@
 pub mod app;
+pub mod env;
+pub mod import;
+pub mod init;
 
 use anyhow::anyhow;
-use athalar_core::{from_path, AthalarAdapter, FinalFile};
-use athalar_python::get_python_contents;
-use std::{fs::File, io::Write, path::PathBuf};
+use athalar_class_validator::ClassValidatorRenderer;
+use athalar_compose::ComposeRenderer;
+use athalar_core::{
+    constants::ATHALAR_CONFIG_FILE,
+    from_path,
+    reporting::{ReportLevel, ValidationReport},
+    AdapterRegistry, AthalarAdapterKind, AthalarConfig, FinalFile,
+};
+use athalar_dotenv::DotenvRenderer;
+use athalar_go::GoRenderer;
+use athalar_json_schema::JsonSchemaRenderer;
+use athalar_kubernetes::KubernetesRenderer;
+use athalar_markdown::MarkdownRenderer;
+use athalar_plugin::PluginRenderer;
+use athalar_python::PythonRenderer;
+use athalar_rust::RustRenderer;
+use athalar_template::TemplateRenderer;
+use athalar_zod::ZodRenderer;
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
+use similar::TextDiff;
+use std::{
+    fmt,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
 
-pub fn run(path: PathBuf) -> anyhow::Result<()> {
-    // TODO: Correct error handling
-    let athalar = from_path(path.to_str().unwrap().to_owned()).expect("msg");
-    let information = athalar.get_information().unwrap();
-    let mut final_files = vec![];
-    for (generator, atoms) in information.generators.iter() {
-        for binding in generator.data.bindings.iter() {
-            let path = binding.output(&information.config.project_source());
-            let contents = match &binding.profile {
-                AthalarAdapter::Pydantic(_a) => get_python_contents(binding, atoms),
-                // TODO: Handle it
-                AthalarAdapter::ClassValidator(_) => continue,
-            }?;
-            final_files.push(FinalFile { contents, path });
-        }
+/// How long to wait for the changes to settle before regenerating in watch mode
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The registry with the renderers for all the adapters that the CLI supports. New
+/// adapters should be registered here.
+pub fn registry() -> AdapterRegistry {
+    let mut registry = AdapterRegistry::default();
+    registry.register(AthalarAdapterKind::ClassValidator, ClassValidatorRenderer);
+    registry.register(AthalarAdapterKind::Pydantic, PythonRenderer);
+    registry.register(AthalarAdapterKind::PydanticSettings, PythonRenderer);
+    registry.register(AthalarAdapterKind::Zod, ZodRenderer);
+    registry.register(AthalarAdapterKind::Rust, RustRenderer);
+    registry.register(AthalarAdapterKind::Go, GoRenderer);
+    registry.register(AthalarAdapterKind::JsonSchema, JsonSchemaRenderer);
+    registry.register(AthalarAdapterKind::Dotenv, DotenvRenderer);
+    registry.register(AthalarAdapterKind::Kubernetes, KubernetesRenderer);
+    registry.register(AthalarAdapterKind::Compose, ComposeRenderer);
+    registry.register(AthalarAdapterKind::Markdown, MarkdownRenderer);
+    registry.register(AthalarAdapterKind::Template, TemplateRenderer);
+    registry.register(AthalarAdapterKind::Plugin, PluginRenderer);
+    registry
+}
+
+/// Prints the problems in a report, and fails if any of them are severe. The informational
+/// ones are left out, along with the outputs that already exist (which they do every time
+/// that the bindings are generated again).
+fn check_report(report: &ValidationReport) -> anyhow::Result<()> {
+    let messages = report
+        .generators
+        .bindings
+        .iter()
+        .map(|(_, message)| message)
+        .chain(report.generators.config.iter().map(|(_, message)| message))
+        .chain(report.partials.config.iter().map(|(_, message)| message))
+        .chain(report.values.config.iter().map(|(_, message)| message))
+        .filter(|m| m.level != ReportLevel::Info && m.code != "FileAlreadyExists");
+    for message in messages {
+        eprintln!("{}: {}", message.level, message.message);
+    }
+    if report.has_errors_with_level(ReportLevel::Severe) {
+        return Err(anyhow!("The project has errors that must be fixed"));
+    }
+    Ok(())
+}
+
+/// Loads the project, prints its validation report and renders all of its bindings in
+/// memory.
+pub fn render(path: PathBuf) -> anyhow::Result<Vec<FinalFile>> {
+    render_project(path, false)
+}
+
+/// Like [render], but the project is validated without changing anything on the disk. Used
+/// by the modes that only compare or print the rendered files.
+pub fn render_read_only(path: PathBuf) -> anyhow::Result<Vec<FinalFile>> {
+    render_project(path, true)
+}
+
+fn render_project(path: PathBuf, read_only: bool) -> anyhow::Result<Vec<FinalFile>> {
+    let athalar = from_path(path.to_string_lossy().to_string()).map_err(|e| anyhow!(e))?;
+    let information = athalar.get_information().map_err(|e| anyhow!(e))?;
+    let report = match read_only {
+        true => athalar.get_read_only_validation_report(),
+        false => athalar.get_validation_report(),
+    };
+    // the renderers can only work with the bindings that pass the checks of the core
+    check_report(&report)?;
+    let mut report = ValidationReport::default();
+    let final_files = registry().render_with_reports(&information, &mut report)?;
+    check_report(&report)?;
+    Ok(final_files)
+}
+
+/// What writing a rendered file would do to the one on disk.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileStatus {
+    Created,
+    Changed,
+    Unchanged,
+}
+
+impl fmt::Display for FileStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Created => "created",
+            Self::Changed => "changed",
+            Self::Unchanged => "unchanged",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+// a file that has not been generated yet has no contents
+fn read_existing(final_file: &FinalFile) -> anyhow::Result<Option<String>> {
+    match final_file.path.exists() {
+        true => fs::read_to_string(&final_file.path)
+            .map(Some)
+            .map_err(|_| anyhow!("Unable to read file {:?}", final_file.path)),
+        false => Ok(None),
     }
+}
+
+/// Compares a rendered file to the one on disk.
+pub fn get_status(final_file: &FinalFile) -> anyhow::Result<FileStatus> {
+    Ok(match read_existing(final_file)? {
+        None => FileStatus::Created,
+        Some(existing) if existing == final_file.contents => FileStatus::Unchanged,
+        Some(_) => FileStatus::Changed,
+    })
+}
+
+/// Writes the rendered files to the disk, skipping the ones whose contents did not change.
+pub fn write(final_files: Vec<FinalFile>) -> anyhow::Result<()> {
     for final_file in final_files {
+        if get_status(&final_file)? == FileStatus::Unchanged {
+            continue;
+        }
         File::create(final_file.path)
             .map_err(|_| anyhow!("Unable to create file"))?
             .write_all(final_file.contents.as_bytes())
@@ -29,3 +156,190 @@ pub fn run(path: PathBuf) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+/// Compares the rendered files to the ones on disk and prints a unified diff for each one
+/// that is out of date. Returns the number of files that are out of date.
+pub fn check(final_files: &[FinalFile]) -> anyhow::Result<usize> {
+    let mut stale = 0;
+    for final_file in final_files {
+        let path = final_file.path.to_string_lossy();
+        // a file that has not been generated yet is compared as if it were empty
+        let existing = read_existing(final_file)?.unwrap_or_default();
+        if existing == final_file.contents {
+            continue;
+        }
+        stale += 1;
+        let diff = TextDiff::from_lines(&existing, &final_file.contents);
+        print!("{}", diff.unified_diff().header(&path, &path));
+    }
+    Ok(stale)
+}
+
+/// Prints the path of each rendered file along with what writing it would do.
+pub fn dry_run(final_files: &[FinalFile]) -> anyhow::Result<()> {
+    for final_file in final_files {
+        println!(
+            "{}: {}",
+            get_status(final_file)?,
+            final_file.path.to_string_lossy()
+        );
+    }
+    Ok(())
+}
+
+/// Prints the contents of each rendered file, preceded by its path.
+pub fn print(final_files: &[FinalFile]) {
+    for final_file in final_files {
+        println!("==> {} <==", final_file.path.to_string_lossy());
+        print!("{}", final_file.contents);
+    }
+}
+
+pub fn run(path: PathBuf) -> anyhow::Result<()> {
+    write(render(path)?)
+}
+
+/// The paths that decide the generated files: the project configuration, the partials, the
+/// generators, the values and the templates of the bindings. The ones that do not exist can
+/// not be watched, so they are left out.
+fn get_watched_paths(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let config_file = path.join(ATHALAR_CONFIG_FILE);
+    let contents = fs::read_to_string(&config_file)
+        .map_err(|_| anyhow!("Unable to read file {:?}", config_file))?;
+    let config = AthalarConfig::from_str_and_source(&contents, &path.to_string_lossy().to_string())
+        .map_err(|e| anyhow!("Unable to parse {:?}: {}", config_file, e))?;
+    let mut paths = vec![
+        config_file,
+        config.partials(),
+        config.generators(),
+        config.values(),
+    ];
+    // the templates are only known when the whole project can be loaded
+    if let Ok(athalar) = from_path(path.to_string_lossy().to_string()) {
+        if let Ok(information) = athalar.get_information() {
+            for (generator, _) in information.generators.iter() {
+                for binding in generator.data.bindings.iter() {
+                    if let Ok(Some(template)) = binding.template(&config.project_source()) {
+                        paths.push(template);
+                    }
+                }
+            }
+        }
+    }
+    paths.retain(|p| p.exists());
+    Ok(paths)
+}
+
+/// Generates the bindings and then again every time the project configuration, the
+/// partials, the generators, the values or the templates change, until the process is
+/// stopped. Errors are printed instead of stopping the watch, so that they can be fixed
+/// while it is running.
+pub fn watch(path: PathBuf) -> anyhow::Result<()> {
+    let mut watched = get_watched_paths(&path)?;
+    let (sender, receiver) = mpsc::channel();
+    let mut debouncer = new_debouncer(WATCH_DEBOUNCE, sender)?;
+    for watched_path in watched.iter() {
+        debouncer
+            .watcher()
+            .watch(watched_path, RecursiveMode::Recursive)?;
+    }
+    loop {
+        match run(path.clone()) {
+            Ok(()) => eprintln!("Generated the bindings, watching for changes"),
+            Err(e) => eprintln!("Error: {}", e),
+        }
+        loop {
+            match receiver.recv()? {
+                Ok(_) => break,
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        // the configuration decides what is watched, and editors that replace a file on
+        // save end its watch, so the watcher is rebuilt after every change (keeping the
+        // earlier paths if the configuration can not be read)
+        if let Ok(paths) = get_watched_paths(&path) {
+            let watcher = debouncer.watcher();
+            for watched_path in watched.iter() {
+                watcher.unwatch(watched_path).ok();
+            }
+            for watched_path in paths.iter() {
+                watcher.watch(watched_path, RecursiveMode::Recursive)?;
+            }
+            watched = paths;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn check_counts_stale_and_missing_files() {
+        let directory = env::temp_dir().join("athalar_cli_check_test");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("fresh.txt"), "fresh\n").unwrap();
+        fs::write(directory.join("stale.txt"), "old\n").unwrap();
+        fs::remove_file(directory.join("missing.txt")).ok();
+        let final_files = ["fresh", "stale", "missing"]
+            .into_iter()
+            .map(|name| FinalFile {
+                path: directory.join(format!("{}.txt", name)),
+                contents: format!("{}\n", name),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(check(&final_files).unwrap(), 2);
+        assert!(!directory.join("missing.txt").exists());
+    }
+
+    #[test]
+    fn read_only_rendering_does_not_touch_the_outputs() {
+        let directory = env::temp_dir().join("athalar_cli_read_only_test");
+        fs::remove_dir_all(&directory).ok();
+        init::init(&directory, &[], &[]).unwrap();
+        let final_files = render_read_only(directory).unwrap();
+        assert!(!final_files.is_empty());
+        assert!(final_files.iter().all(|f| !f.path.exists()));
+    }
+
+    #[test]
+    fn invalid_values_stop_the_rendering() {
+        let directory = env::temp_dir().join("athalar_cli_invalid_values_test");
+        fs::remove_dir_all(&directory).ok();
+        init::init(&directory, &[], &[]).unwrap();
+        let values = directory.join("src/values");
+        fs::create_dir_all(&values).unwrap();
+        fs::write(
+            values.join("production.ath.yaml"),
+            "values:\n  APP_PORT: http\n",
+        )
+        .unwrap();
+        assert!(render(directory).is_err());
+    }
+
+    #[test]
+    fn status_compares_contents_on_disk() {
+        let directory = env::temp_dir().join("athalar_cli_status_test");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("config.txt"), "old\n").unwrap();
+        fs::remove_file(directory.join("missing.txt")).ok();
+        let final_file = |name: &str, contents: &str| FinalFile {
+            path: directory.join(name),
+            contents: contents.to_string(),
+        };
+        let status = |f: FinalFile| get_status(&f).unwrap();
+        assert_eq!(
+            status(final_file("config.txt", "old\n")),
+            FileStatus::Unchanged
+        );
+        assert_eq!(
+            status(final_file("config.txt", "new\n")),
+            FileStatus::Changed
+        );
+        assert_eq!(
+            status(final_file("missing.txt", "new\n")),
+            FileStatus::Created
+        );
+    }
+}
@@ -1,17 +1,127 @@
 use athalar_cli::{
-    app::{App, Commands},
-    run,
+    app::{App, Commands, EnvCommand, ImportSource},
+    check, dry_run,
+    env::{check_environment, get_values, render_values},
+    import::{import_dotenv, import_pydantic},
+    init::init,
+    print, render, render_read_only, watch, write,
 };
+use athalar_core::reporting::ReportLevel;
 use clap::Parser;
-use std::env;
+use std::{env, fs};
 
 fn main() -> anyhow::Result<()> {
     let cli = App::parse();
     match cli.command {
-        Commands::Generate { path } => {
+        Commands::Generate {
+            path,
+            check: check_only,
+            dry_run: dry_run_only,
+            stdout,
+            watch: watch_mode,
+        } => {
             let path = path
                 .unwrap_or_else(|| env::current_dir().expect("Unable to get current directory"));
-            run(path)?;
+            if watch_mode {
+                return watch(path);
+            }
+            // only writing the files is allowed to change anything on the disk
+            let final_files = match check_only || dry_run_only || stdout {
+                true => render_read_only(path)?,
+                false => render(path)?,
+            };
+            if check_only {
+                let stale = check(&final_files)?;
+                if stale > 0 {
+                    anyhow::bail!("{} generated file(s) are out of date", stale);
+                }
+            } else if dry_run_only {
+                dry_run(&final_files)?;
+            } else if stdout {
+                print(&final_files);
+            } else {
+                write(final_files)?;
+            }
+        }
+        Commands::Init {
+            path,
+            adapters,
+            outputs,
+        } => {
+            let path = path
+                .unwrap_or_else(|| env::current_dir().expect("Unable to get current directory"));
+            init(&path, &adapters, &outputs)?;
+            println!("Created a new project at {:?}", path);
+        }
+        Commands::Import {
+            source:
+                ImportSource::Dotenv {
+                    file,
+                    partial,
+                    project,
+                },
+        } => {
+            let project = project
+                .unwrap_or_else(|| env::current_dir().expect("Unable to get current directory"));
+            let output = import_dotenv(&project, &file, &partial)?;
+            println!("Created the partial at {:?}", output);
+        }
+        Commands::Import {
+            source:
+                ImportSource::Pydantic {
+                    file,
+                    class_name,
+                    partial,
+                    project,
+                },
+        } => {
+            let project = project
+                .unwrap_or_else(|| env::current_dir().expect("Unable to get current directory"));
+            let output = import_pydantic(&project, &file, &class_name, &partial)?;
+            println!("Created the partial at {:?}", output);
+        }
+        Commands::Env {
+            command:
+                EnvCommand::Check {
+                    generator,
+                    env_file,
+                    prefix,
+                    project,
+                },
+        } => {
+            let project = project
+                .unwrap_or_else(|| env::current_dir().expect("Unable to get current directory"));
+            let values = get_values(env_file.as_deref())?;
+            let reports = check_environment(&project, &generator, &values, prefix.as_deref())?;
+            for report in reports.iter() {
+                println!("{}: {}", report.level, report.message);
+            }
+            let errors = reports
+                .iter()
+                .filter(|r| r.level == ReportLevel::Severe)
+                .count();
+            if errors > 0 {
+                anyhow::bail!("The environment has {} error(s)", errors);
+            }
+        }
+        Commands::Env {
+            command:
+                EnvCommand::Render {
+                    environment,
+                    generator,
+                    output,
+                    project,
+                },
+        } => {
+            let project = project
+                .unwrap_or_else(|| env::current_dir().expect("Unable to get current directory"));
+            let values = env::vars().collect();
+            let contents = render_values(&project, &environment, &generator, &values)?;
+            match output {
+                Some(output) => fs::write(&output, contents)
+                    .map_err(|_| anyhow::anyhow!("Unable to write file {:?}", output))?,
+                None => print!("{}", contents),
+            }
         }
     };
     Ok(())
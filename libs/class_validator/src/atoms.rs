@@ -0,0 +1,57 @@
+use athalar_core::{AtomKind, AtomValidator};
+use strum_macros::Display;
+
+#[derive(Debug, Display)]
+pub enum AthalarClassValidatorKind {
+    #[strum(serialize = "number")]
+    Number,
+
+    #[strum(serialize = "string")]
+    String,
+
+    #[strum(serialize = "any")]
+    Any,
+}
+
+impl From<AtomKind> for AthalarClassValidatorKind {
+    fn from(av: AtomKind) -> Self {
+        match av {
+            AtomKind::Number => Self::Number,
+            AtomKind::String => Self::String,
+            AtomKind::Any => Self::Any,
+        }
+    }
+}
+
+/// The decorators exported by `class-validator` that correspond to each validator.
+#[derive(Debug, Display)]
+pub enum AthalarClassValidatorDecorator {
+    #[strum(serialize = "IsNumber")]
+    Number,
+
+    #[strum(serialize = "IsString")]
+    String,
+
+    #[strum(serialize = "IsUrl")]
+    Url,
+
+    #[strum(serialize = "IsPort")]
+    Port,
+
+    #[strum(serialize = "IsOptional")]
+    Optional,
+
+    Allow,
+}
+
+impl From<AtomValidator> for AthalarClassValidatorDecorator {
+    fn from(av: AtomValidator) -> Self {
+        match av {
+            AtomValidator::Number => Self::Number,
+            AtomValidator::String => Self::String,
+            AtomValidator::Url => Self::Url,
+            AtomValidator::Port => Self::Port,
+            AtomValidator::Noop => Self::Allow,
+        }
+    }
+}
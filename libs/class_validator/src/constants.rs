@@ -0,0 +1 @@
+pub const DEFAULT_CLASS_NAME: &str = "ApplicationConfig";
@@ -0,0 +1,192 @@
+mod atoms;
+mod constants;
+
+use crate::{
+    atoms::{AthalarClassValidatorDecorator, AthalarClassValidatorKind},
+    constants::DEFAULT_CLASS_NAME,
+};
+use athalar_core::{
+    render_template, AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde::{Deserialize, Serialize};
+use tera::Context as TeraContext;
+
+const CLASS_VALIDATOR_TEMPLATE: &str = include_str!("class_validator.tera");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PropertyContext {
+    name: String,
+    kind: String,
+    decorators: Vec<String>,
+    optional: bool,
+    default: Option<String>,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Context {
+    class_name: String,
+    imports: Vec<String>,
+    properties: Vec<PropertyContext>,
+}
+
+pub fn get_class_validator_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let mut context = match &binding.profile {
+        AthalarAdapter::ClassValidator(x) => Context {
+            class_name: x
+                .class_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_CLASS_NAME.to_string()),
+            imports: vec![],
+            properties: vec![],
+        },
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let mut imports: Vec<String> = vec![];
+    for atom in atoms {
+        let mut decorators = atom
+            .validators
+            .iter()
+            .map(|v| AthalarClassValidatorDecorator::from(v.clone()))
+            .collect::<Vec<_>>();
+        // `class-validator` strips properties without decorators when whitelisting
+        if decorators.is_empty() {
+            decorators.push(AthalarClassValidatorDecorator::Allow);
+        }
+        // the other decorators are skipped when the variable is not set
+        if !atom.is_required() {
+            decorators.insert(0, AthalarClassValidatorDecorator::Optional);
+        }
+        let decorators = decorators
+            .into_iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>();
+        imports.extend(decorators.iter().cloned());
+        context.properties.push(PropertyContext {
+            name: atom.name.clone(),
+            kind: AthalarClassValidatorKind::from(atom.kind).to_string(),
+            decorators,
+            // a variable with a default is always set, so it does not need to be optional
+            optional: atom.optional && atom.default_value.is_none(),
+            default: atom.get_default_literal(),
+            comment: atom.description.clone(),
+        })
+    }
+    imports.sort();
+    imports.dedup();
+    context.imports = imports;
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = render_template(CLASS_VALIDATOR_TEMPLATE, &context)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the class validator profile.
+pub struct ClassValidatorRenderer;
+
+impl FileRenderer for ClassValidatorRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_class_validator_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator,
+        ClassValidatorAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    fn binding() -> AthalarBinding {
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from("backend.ts"))
+            .profile(AthalarAdapter::ClassValidator(
+                ClassValidatorAdapterProfileBuilder::default()
+                    .class_name("BackendConfig")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn renders_decorators_and_sorted_imports() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .description(Some("The mail port".to_string()))
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("MAIL_HOST")
+                .validators(vec![AtomValidator::String])
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_class_validator_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains(r#"import { IsPort, IsString } from "class-validator";"#));
+        assert!(contents.contains("export class BackendConfig {"));
+        assert!(contents.contains("   * The mail port\n   */\n  @IsPort()\n  MAIL_PORT: number;"));
+        assert!(contents.contains("  @IsString()\n  MAIL_HOST: string;"));
+    }
+
+    #[test]
+    fn every_line_of_a_description_is_in_the_comment() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .description(Some("The mail port\nused for alerts\n".to_string()))
+            .build()
+            .unwrap()];
+        let contents = get_class_validator_contents(&binding(), &atoms).unwrap();
+        assert!(contents
+            .contains("  /**\n   * The mail port\n   * used for alerts\n   */\n  @IsPort()\n"));
+    }
+
+    #[test]
+    fn atoms_without_validators_are_allowed() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_HOST")
+            .validators(vec![AtomValidator::Noop])
+            .build()
+            .unwrap()];
+        let contents = get_class_validator_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains("  @Allow()\n  MAIL_HOST: any;"));
+    }
+
+    #[test]
+    fn optional_atoms_and_defaults_can_be_left_unset() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_class_validator_contents(&binding(), &atoms).unwrap();
+        assert!(
+            contents.contains(r#"import { IsOptional, IsPort, IsUrl } from "class-validator";"#)
+        );
+        assert!(contents.contains("  @IsOptional()\n  @IsPort()\n  MAIL_PORT: number = 25;"));
+        assert!(contents.contains("  @IsOptional()\n  @IsUrl()\n  API_URL?: string;"));
+    }
+}
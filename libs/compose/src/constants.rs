@@ -0,0 +1,5 @@
+pub const DEFAULT_SERVICE: &str = "app";
+pub const LEADING_COMMENT: &str = "# -----------------------------------------------------
+# THIS FILE WAS AUTOMATICALLY GENERATED (DO NOT MODIFY)
+# -----------------------------------------------------
+";
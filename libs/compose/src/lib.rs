@@ -0,0 +1,115 @@
+mod constants;
+
+use crate::constants::{DEFAULT_SERVICE, LEADING_COMMENT};
+use athalar_core::{
+    AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde_yaml::{Mapping, Value};
+
+/// Returns the interpolation that compose performs for an atom. Required atoms make
+/// compose fail when they are not set, and optional atoms without a default are passed
+/// through from the shell only if they are set.
+fn get_interpolation(atom: &AthalarAtom) -> Value {
+    // secrets never get their default written, so they need to be supplied
+    match (&atom.default_value, atom.secret) {
+        (Some(default_value), false) => Value::from(format!(
+            "${{{}:-{}}}",
+            atom.name,
+            default_value.replace('$', "$$")
+        )),
+        _ if atom.optional => Value::Null,
+        _ => Value::from(format!("${{{0}:?{0} must be set}}", atom.name)),
+    }
+}
+
+pub fn get_compose_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let service = match &binding.profile {
+        AthalarAdapter::Compose(x) => x
+            .service
+            .clone()
+            .unwrap_or_else(|| DEFAULT_SERVICE.to_string()),
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let mut environment = Mapping::new();
+    for atom in atoms {
+        environment.insert(Value::from(atom.name.clone()), get_interpolation(atom));
+    }
+    let mut service_definition = Mapping::new();
+    service_definition.insert(Value::from("environment"), Value::from(environment));
+    let mut services = Mapping::new();
+    services.insert(Value::from(service), Value::from(service_definition));
+    let mut document = Mapping::new();
+    document.insert(Value::from("services"), Value::from(services));
+    let mut rendered = LEADING_COMMENT.to_string();
+    rendered.push_str(&serde_yaml::to_string(&document)?);
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the compose profile.
+pub struct ComposeRenderer;
+
+impl FileRenderer for ComposeRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_compose_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, ComposeAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn atoms_are_interpolated() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("docker-compose.override.yaml"))
+            .profile(AthalarAdapter::Compose(
+                ComposeAdapterProfileBuilder::default()
+                    .service("backend")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("MAIL_HOST")
+                .validators(vec![AtomValidator::String])
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_compose_contents(&binding, &atoms).unwrap();
+        let document = serde_yaml::from_str::<Value>(&contents).unwrap();
+        let environment = &document["services"]["backend"]["environment"];
+        assert_eq!(environment["MAIL_PORT"], Value::from("${MAIL_PORT:-25}"));
+        assert_eq!(
+            environment["MAIL_HOST"],
+            Value::from("${MAIL_HOST:?MAIL_HOST must be set}")
+        );
+        assert_eq!(environment["API_URL"], Value::Null);
+    }
+}
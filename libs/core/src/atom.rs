@@ -1,14 +1,14 @@
-use crate::utils::get_uuid;
 use derive_builder::Builder;
 use hashbag::HashBag;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml::Value;
 use std::iter::FromIterator;
 use strum_macros::Display;
 use uuid::Uuid;
 
-// Note: It is the job of the client libraries to actually perform the validation and apply
-// the correct modification to the code (for example decorators for class-validator
-// bindings).
+// Note: It is the job of the client libraries to apply the correct modification to the
+// code (for example decorators for class-validator bindings). The canonical behavior of
+// each validator is implemented by `AtomValidator::is_valid`.
 /// The different validators that can be applied to the different configuration variables
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Display)]
 pub enum AtomValidator {
@@ -55,9 +55,12 @@ impl From<AtomValidator> for AtomKind {
 #[derive(Debug, PartialEq, Eq, Builder, Clone, Default, Serialize, Deserialize)]
 #[builder(derive(Debug, Serialize, Deserialize))]
 pub struct AthalarAtom {
-    /// A unique ID assigned to this atom, should be used as an identifier
+    /// A unique ID assigned to this atom, should be used as an identifier. It is derived
+    /// from the partial and the position of the atom in it, see [load_partials].
+    ///
+    /// [load_partials]: crate::utils::load_partials
     #[builder(field(type = "Uuid"))]
-    #[builder_field_attr(serde(default = "get_uuid"))]
+    #[builder_field_attr(serde(skip))]
     pub(crate) id: Uuid,
 
     /// The name of this configuration variable
@@ -76,6 +79,78 @@ pub struct AthalarAtom {
     /// An optional description that will get included in the generated code
     #[builder(setter(into), default)]
     pub description: Option<String>,
+
+    /// Whether the configuration variable can be left unset
+    #[builder(default)]
+    #[serde(default)]
+    pub optional: bool,
+
+    /// The value that should be used when the configuration variable is not set. It is
+    /// stored the way it would appear in the environment.
+    #[builder(setter(into, strip_option), default)]
+    #[builder_field_attr(serde(
+        default,
+        rename = "default",
+        deserialize_with = "deserialize_default"
+    ))]
+    #[serde(default, rename = "default")]
+    pub default_value: Option<String>,
+
+    /// Whether the value of this configuration variable is sensitive and should never be
+    /// written in plain text
+    #[builder(default)]
+    #[serde(default)]
+    pub secret: bool,
+
+    /// Names that this configuration variable was known by earlier. They are still
+    /// accepted, but should be replaced by the current name.
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub deprecated_names: Vec<String>,
+
+    /// The name of the partial that this atom was declared in, set when the partials are
+    /// loaded, see [load_partials].
+    ///
+    /// [load_partials]: crate::utils::load_partials
+    #[builder(setter(skip), default)]
+    #[builder_field_attr(serde(skip))]
+    #[serde(skip)]
+    pub partial: Option<String>,
+}
+
+impl AthalarAtom {
+    /// Whether a value must be supplied for this configuration variable.
+    pub fn is_required(&self) -> bool {
+        !self.optional && self.default_value.is_none()
+    }
+
+    /// The default of this atom as a JSON literal, which javascript (among others) reads as
+    /// is. Numbers are written as they are, everything else as a string.
+    pub fn get_default_literal(&self) -> Option<String> {
+        let value = self.default_value.as_ref()?;
+        match self.kind {
+            AtomKind::Number if value.parse::<f64>().is_ok_and(|n| n.is_finite()) => {
+                Some(value.clone())
+            }
+            _ => Some(serde_json::Value::from(value.as_str()).to_string()),
+        }
+    }
+}
+
+// defaults are written as plain yaml scalars (eg: `default: 25`), but are stored as strings
+// since that is how they would be present in the environment
+fn deserialize_default<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => None,
+        Some(Value::String(s)) => Some(s),
+        Some(Value::Number(n)) => Some(n.to_string()),
+        Some(Value::Bool(b)) => Some(b.to_string()),
+        Some(_) => return Err(serde::de::Error::custom("default must be a scalar value")),
+    };
+    Ok(Some(value))
 }
 
 impl AthalarAtomBuilder {
@@ -89,12 +164,17 @@ impl AthalarAtomBuilder {
                 let counts: HashBag<AtomKind> = HashBag::from_iter(
                     self.validators
                         .clone()
-                        .unwrap()
+                        .unwrap_or_default()
                         .into_iter()
                         .map(AtomKind::from),
                 );
-                let max = counts.set_iter().max_by_key(|x| x.1).unwrap();
-                Ok(*max.0)
+                match counts.set_iter().max_by_key(|x| x.1) {
+                    Some(max) => Ok(*max.0),
+                    None => Err(format!(
+                        "{:?} needs a kind or at least one validator",
+                        self.name.clone().unwrap_or_default()
+                    )),
+                }
             }
         }
     }
@@ -113,4 +193,51 @@ mod test {
             .unwrap();
         assert_eq!(aca.validators.len(), 1);
     }
+
+    #[test]
+    fn scalar_defaults_are_stored_as_strings() {
+        let aca = serde_yaml::from_str::<AthalarAtomBuilder>(
+            "name: MAIL_PORT\ndefault: 25\nvalidators:\n  - !Port",
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+        assert_eq!(aca.default_value, Some("25".to_string()));
+        assert!(!aca.is_required());
+    }
+
+    #[test]
+    fn defaults_are_written_as_literals() {
+        let atom = |validator: AtomValidator, default: &str| {
+            AthalarAtomBuilder::default()
+                .name("MAIL")
+                .validators(vec![validator])
+                .default_value(default)
+                .build()
+                .unwrap()
+        };
+        assert_eq!(
+            atom(AtomValidator::Port, "25").get_default_literal(),
+            Some("25".to_string())
+        );
+        assert_eq!(
+            atom(AtomValidator::Number, "inf").get_default_literal(),
+            Some("\"inf\"".to_string())
+        );
+        assert_eq!(
+            atom(AtomValidator::String, "mail \"local\"").get_default_literal(),
+            Some("\"mail \\\"local\\\"\"".to_string())
+        );
+    }
+
+    #[test]
+    fn atoms_without_defaults_are_required() {
+        let aca = AthalarAtomBuilder::default()
+            .name("mail")
+            .validators(vec![AtomValidator::String])
+            .build()
+            .unwrap();
+        assert_eq!(aca.default_value, None);
+        assert!(aca.is_required());
+    }
 }
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the docker compose adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct ComposeAdapterProfile {
+    /// The name of the compose service that the environment belongs to
+    #[builder(setter(into, strip_option), default)]
+    pub service: Option<String>,
+}
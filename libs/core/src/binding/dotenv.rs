@@ -0,0 +1,11 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the dotenv adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct DotenvAdapterProfile {
+    /// Whether optional variables without a default should be written as comments, so
+    /// that they stay unset when the file is loaded
+    #[builder(setter(into, strip_option), default)]
+    pub comment_optional: Option<bool>,
+}
@@ -0,0 +1,14 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the go adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct GoAdapterProfile {
+    /// The name of the package the generated file belongs to
+    #[builder(setter(into, strip_option), default)]
+    pub package_name: Option<String>,
+
+    /// The name of the struct generated
+    #[builder(setter(into, strip_option), default)]
+    pub struct_name: Option<String>,
+}
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the JSON schema adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct JsonSchemaAdapterProfile {
+    /// The title of the schema generated
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+}
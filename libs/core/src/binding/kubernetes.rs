@@ -0,0 +1,23 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the kubernetes adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct KubernetesAdapterProfile {
+    /// The name of the `ConfigMap` generated
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+
+    /// The name of the `Secret` generated, defaults to the name of the `ConfigMap` with a
+    /// `-secret` suffix
+    #[builder(setter(into, strip_option), default)]
+    pub secret_name: Option<String>,
+
+    /// The namespace that the resources should be created in
+    #[builder(setter(into, strip_option), default)]
+    pub namespace: Option<String>,
+
+    /// Whether to include an `envFrom` snippet that can be copied into a `Deployment`
+    #[builder(setter(into, strip_option), default)]
+    pub env_from: Option<bool>,
+}
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the markdown documentation adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct MarkdownAdapterProfile {
+    /// The title of the document generated
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+}
@@ -1,27 +1,55 @@
 mod class_validator;
+mod compose;
+mod dotenv;
+mod go;
+mod json_schema;
+mod kubernetes;
+mod markdown;
+mod plugin;
 mod pydantic;
+mod rust;
+mod template;
+mod zod;
 
-use crate::utils::get_uuid;
-use class_validator::ClassValidatorAdapterProfile;
 use derive_builder::Builder;
-use relative_path::RelativePath;
+use relative_path::{FromPathError, RelativePath};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use strum_macros::{Display, EnumDiscriminants};
 use uuid::Uuid;
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq)]
+/// The adapters that a binding can use, along with the settings specific to them. The
+/// [kind](AthalarAdapterKind) of an adapter can be used to identify it without its
+/// settings.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq, EnumDiscriminants)]
+#[strum_discriminants(name(AthalarAdapterKind), derive(Hash, Display))]
 pub enum AthalarAdapter {
     ClassValidator(ClassValidatorAdapterProfile),
     Pydantic(PydanticAdapterProfile),
+    PydanticSettings(PydanticSettingsAdapterProfile),
+    Zod(ZodAdapterProfile),
+    Rust(RustAdapterProfile),
+    Go(GoAdapterProfile),
+    JsonSchema(JsonSchemaAdapterProfile),
+    Dotenv(DotenvAdapterProfile),
+    Kubernetes(KubernetesAdapterProfile),
+    Compose(ComposeAdapterProfile),
+    Markdown(MarkdownAdapterProfile),
+    Template(TemplateAdapterProfile),
+    Plugin(PluginAdapterProfile),
 }
 
 /// A binding is a set of configuration for a specific language.
 #[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
 #[builder(derive(Debug, Serialize, Deserialize))]
 pub struct AthalarBinding {
-    /// A unique ID assigned to this binding, should be used as an identifier
+    /// A unique ID assigned to this binding, should be used as an identifier. It is
+    /// derived from the generator and the position of the binding in it, see
+    /// [load_generators].
+    ///
+    /// [load_generators]: crate::utils::load_generators
     #[builder(field(type = "Uuid"))]
-    #[builder_field_attr(serde(default = "get_uuid"))]
+    #[builder_field_attr(serde(skip))]
     pub(crate) id: Uuid,
 
     // The user will declare this path to be relative to `athalar.toml` but we will fully
@@ -40,7 +68,41 @@ impl AthalarBinding {
         let b = RelativePath::from_path(&self.output).unwrap();
         b.to_logical_path(source)
     }
+
+    /// The path of the tera template that the profile of this binding renders (as it was
+    /// declared), if it uses one supplied by the project.
+    pub fn template_path(&self) -> Option<&PathBuf> {
+        match &self.profile {
+            AthalarAdapter::Pydantic(x) => x.template.as_ref(),
+            AthalarAdapter::PydanticSettings(x) => x.template.as_ref(),
+            AthalarAdapter::Template(x) => Some(&x.path),
+            _ => None,
+        }
+    }
+
+    /// Takes a source path and returns the logical path of the tera template that the
+    /// profile of this binding renders, if it uses one supplied by the project. Fails if
+    /// the template was not declared relative to the source (eg: an absolute path).
+    pub fn template(&self, source: &Path) -> Result<Option<PathBuf>, FromPathError> {
+        match self.template_path() {
+            Some(path) => Ok(Some(RelativePath::from_path(path)?.to_logical_path(source))),
+            None => Ok(None),
+        }
+    }
 }
 
-pub use class_validator::ClassValidatorAdapterProfileBuilder;
-pub use pydantic::PydanticAdapterProfile;
+pub use class_validator::{ClassValidatorAdapterProfile, ClassValidatorAdapterProfileBuilder};
+pub use compose::{ComposeAdapterProfile, ComposeAdapterProfileBuilder};
+pub use dotenv::{DotenvAdapterProfile, DotenvAdapterProfileBuilder};
+pub use go::{GoAdapterProfile, GoAdapterProfileBuilder};
+pub use json_schema::{JsonSchemaAdapterProfile, JsonSchemaAdapterProfileBuilder};
+pub use kubernetes::{KubernetesAdapterProfile, KubernetesAdapterProfileBuilder};
+pub use markdown::{MarkdownAdapterProfile, MarkdownAdapterProfileBuilder};
+pub use plugin::{PluginAdapterProfile, PluginAdapterProfileBuilder};
+pub use pydantic::{
+    PydanticAdapterProfile, PydanticAdapterProfileBuilder, PydanticSettingsAdapterProfile,
+    PydanticSettingsAdapterProfileBuilder,
+};
+pub use rust::{RustAdapterProfile, RustAdapterProfileBuilder};
+pub use template::{TemplateAdapterProfile, TemplateAdapterProfileBuilder};
+pub use zod::{ZodAdapterProfile, ZodAdapterProfileBuilder};
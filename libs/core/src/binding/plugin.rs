@@ -0,0 +1,23 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Mapping;
+
+/// Settings that are specific to the plugin adapter, which runs an external executable to
+/// generate the files of a binding
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct PluginAdapterProfile {
+    /// The executable to run. It is looked up in `PATH` when it is a plain name and used as
+    /// is when it is an absolute path, otherwise it is relative to `athalar.toml`.
+    #[builder(setter(into))]
+    pub command: String,
+
+    /// The arguments that the executable should be run with
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Arbitrary options that are sent to the plugin
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub options: Mapping,
+}
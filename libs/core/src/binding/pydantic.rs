@@ -1,5 +1,6 @@
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 // TODO: Move this file to the python crate
 /// Settings that are specific to the pydantic adapter
@@ -8,4 +9,31 @@ pub struct PydanticAdapterProfile {
     /// The name of the class generated
     #[builder(setter(into, strip_option), default)]
     pub class_name: Option<String>,
+
+    /// The path to a tera template (relative to `athalar.toml`) that should be rendered
+    /// instead of the built-in one
+    #[builder(setter(into, strip_option), default)]
+    pub template: Option<PathBuf>,
+}
+
+/// Settings that are specific to the pydantic settings adapter, which generates a class
+/// that reads and validates the environment when it is constructed
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct PydanticSettingsAdapterProfile {
+    /// The name of the class generated
+    #[builder(setter(into, strip_option), default)]
+    pub class_name: Option<String>,
+
+    /// The prefix that the environment variables are expected to have
+    #[builder(setter(into, strip_option), default)]
+    pub env_prefix: Option<String>,
+
+    /// The dotenv file that should be read in addition to the environment
+    #[builder(setter(into, strip_option), default)]
+    pub env_file: Option<String>,
+
+    /// The path to a tera template (relative to `athalar.toml`) that should be rendered
+    /// instead of the built-in one
+    #[builder(setter(into, strip_option), default)]
+    pub template: Option<PathBuf>,
 }
@@ -0,0 +1,11 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the rust adapter. The generated code depends on the `serde`
+/// and `url` crates.
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct RustAdapterProfile {
+    /// The name of the struct generated
+    #[builder(setter(into, strip_option), default)]
+    pub struct_name: Option<String>,
+}
@@ -0,0 +1,18 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Mapping;
+use std::path::PathBuf;
+
+/// Settings that are specific to the template adapter, which renders a user supplied tera
+/// template
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct TemplateAdapterProfile {
+    /// The path to the template, relative to `athalar.toml`
+    #[builder(setter(into))]
+    pub path: PathBuf,
+
+    /// Arbitrary options that are made available to the template
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub options: Mapping,
+}
@@ -0,0 +1,14 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that are specific to the zod adapter
+#[derive(Debug, PartialEq, Clone, Builder, Serialize, Deserialize, Eq)]
+pub struct ZodAdapterProfile {
+    /// The name of the schema generated
+    #[builder(setter(into, strip_option), default)]
+    pub schema_name: Option<String>,
+
+    /// The name of the type inferred from the schema
+    #[builder(setter(into, strip_option), default)]
+    pub type_name: Option<String>,
+}
@@ -27,13 +27,16 @@ pub struct AthalarConfig {
 
     #[builder(default = "PathBuf::from(\"generators\")")]
     generators: PathBuf,
+
+    #[builder(default = "PathBuf::from(\"values\")")]
+    values: PathBuf,
 }
 
 impl AthalarConfig {
     pub fn from_str_and_source(s: &str, project_src: &String) -> Result<Self, Box<dyn Error>> {
         let project_src = PathBuf::from(project_src);
-        let acb = toml::from_str::<AthalarConfigBuilder>(s).unwrap();
-        let mut athalar_config = acb.build().unwrap();
+        let acb = toml::from_str::<AthalarConfigBuilder>(s)?;
+        let mut athalar_config = acb.build()?;
         athalar_config.source = project_src.join(athalar_config.source);
         Ok(athalar_config)
     }
@@ -48,6 +51,11 @@ impl AthalarConfig {
         self.source.join(self.generators.clone())
     }
 
+    /// The directory where the values of the different environments will be located
+    pub fn values(&self) -> PathBuf {
+        self.source.join(self.values.clone())
+    }
+
     /// The directory where the project is present
     pub fn project_source(&self) -> PathBuf {
         self.source.parent().unwrap().to_path_buf()
@@ -78,6 +86,7 @@ mod test {
             .unwrap();
         assert_eq!(ac.partials, PathBuf::from("partials"));
         assert_eq!(ac.generators, PathBuf::from("generators"));
+        assert_eq!(ac.values, PathBuf::from("values"));
     }
 
     #[test]
@@ -4,12 +4,33 @@ use crate::{
     generator::{AthalarGenerator, AthalarGeneratorContent},
     partial::AthalarPartial,
     reporting::{
-        data::{GeneratorReportCreator, PartialReportCreator},
+        data::{
+            EnvironmentReportCreator, GeneratorReportCreator, PartialReportCreator,
+            ValuesReportCreator,
+        },
         ValidationReport,
     },
-    utils::{load_generators, load_partials},
+    utils::{load_generators, load_partials, load_values},
+    value::ValidatedValues,
+    values::{AthalarValue, AthalarValues},
 };
-use std::fs;
+use std::{collections::HashMap, error::Error, fs, path::Path};
+use tera::Tera;
+
+// whether a file that does not exist yet can be created, either by creating (and removing)
+// it or by checking that its directory exists and is writable
+fn can_create(path: &Path, create: bool) -> bool {
+    if create {
+        return match fs::write(path, "temp") {
+            Ok(_) => fs::remove_file(path).is_ok(),
+            Err(_) => false,
+        };
+    }
+    match path.parent().map(fs::metadata) {
+        Some(Ok(metadata)) => metadata.is_dir() && !metadata.permissions().readonly(),
+        _ => false,
+    }
+}
 
 /// The root instance that manipulates and stores data about an Athalar project. When
 /// generating information about a project, it does so in the following phases:
@@ -36,59 +57,118 @@ pub struct Athalar {
 
     /// The generators that were discovered in this run
     generators: Vec<AthalarGenerator>,
+
+    /// The values of the environments that were discovered in this run
+    values: Vec<AthalarValues>,
 }
 
 impl Athalar {
     /// Takes the project configuration and finds and loads all the relevant athalar
-    /// configuration files.
-    pub fn from_config(config: AthalarConfig) -> Self {
-        let partials = load_partials(&config.partials());
-        let generators = load_generators(&config.generators());
-        Self {
+    /// configuration files. Fails if one of the files can not be read or parsed.
+    pub fn from_config(config: AthalarConfig) -> Result<Self, String> {
+        let root = config.project_source();
+        let partials = load_partials(&config.partials(), &root)?;
+        let generators = load_generators(&config.generators(), &root)?;
+        let values = load_values(&config.values(), &root)?;
+        Ok(Self {
             config,
             partials,
             generators,
-        }
+            values,
+        })
+    }
+
+    /// The configuration that this instance was created with.
+    pub fn config(&self) -> &AthalarConfig {
+        &self.config
     }
 
     /// Once the project files are loaded, this runs a validation on all the collected data
     /// and returns it so that it can be displayed to the end user. It is up to the
     /// consuming library on how it decides to handle this report and whether to force the
     /// user to rectify these errors or allow them to continue.
-    pub fn get_validation_report(&self) -> ValidationReport {
+    ///
+    /// Whether the output of a binding can be created is checked by creating it (and then
+    /// removing it), see [get_read_only_validation_report](Self::get_read_only_validation_report)
+    /// for a check that does not touch the disk.
+    pub fn get_validation_report(&self) -> ValidationReport<'_> {
+        self.get_report(true)
+    }
+
+    /// Runs the same validation as [get_validation_report](Self::get_validation_report),
+    /// without changing anything on the disk. An output is assumed to be creatable if its
+    /// directory exists and is not read-only, which is not as reliable as creating it.
+    pub fn get_read_only_validation_report(&self) -> ValidationReport<'_> {
+        self.get_report(false)
+    }
+
+    fn get_report(&self, create_outputs: bool) -> ValidationReport<'_> {
         let mut reporter = ValidationReport::default();
         // handle generators
-        self.set_generator_binding_errors(&mut reporter);
+        self.set_generator_binding_errors(&mut reporter, create_outputs);
         self.set_generator_config_errors(&mut reporter);
         // handle partials
         self.set_partial_config_errors(&mut reporter);
+        // handle values
+        self.set_values_errors(&mut reporter);
         reporter
     }
 
-    fn set_generator_binding_errors<'a>(&'a self, reporter: &mut ValidationReport<'a>) {
+    fn set_generator_binding_errors<'a>(
+        &'a self,
+        reporter: &mut ValidationReport<'a>,
+        create_outputs: bool,
+    ) {
         self.generators.iter().for_each(|g| {
             let generator_dir = &self.config.project_source();
             // dbg!(&generator_dir);
             g.data.bindings.iter().for_each(|b| {
                 if b.output(generator_dir).exists() {
+                    // if file already exists, we can assume it can be created
                     reporter.add_generator_binding_report(
                         b,
                         GeneratorReportCreator::file_already_exists(
                             &b.output(generator_dir).to_string_lossy(),
                         ),
                     );
-                } else {
-                    // if file already exists, we can assume it can be created
-                    if fs::write(&b.output(generator_dir), "temp").is_ok() {
-                        fs::remove_file(&b.output(generator_dir)).unwrap();
-                    } else {
+                } else if !can_create(&b.output(generator_dir), create_outputs) {
+                    reporter.add_generator_binding_report(
+                        b,
+                        GeneratorReportCreator::can_not_create_file(
+                            &b.output(generator_dir).to_string_lossy(),
+                        ),
+                    );
+                }
+                let template = match b.template(generator_dir) {
+                    Ok(template) => template,
+                    Err(e) => {
+                        // the path of the template is only known as it was declared
+                        let location = b.template_path().unwrap().to_string_lossy();
                         reporter.add_generator_binding_report(
                             b,
-                            GeneratorReportCreator::can_not_create_file(
-                                &b.output(generator_dir).to_string_lossy(),
-                            ),
+                            GeneratorReportCreator::invalid_template(&location, &e.to_string()),
                         );
-                    };
+                        None
+                    }
+                };
+                if let Some(template) = template {
+                    let location = template.to_string_lossy();
+                    match fs::read_to_string(&template) {
+                        Ok(contents) => {
+                            if let Err(e) = Tera::default().add_raw_template(&location, &contents) {
+                                // the parse error itself is the source of the tera error
+                                let reason = e.source().unwrap_or(&e).to_string();
+                                reporter.add_generator_binding_report(
+                                    b,
+                                    GeneratorReportCreator::invalid_template(&location, &reason),
+                                );
+                            }
+                        }
+                        Err(_) => reporter.add_generator_binding_report(
+                            b,
+                            GeneratorReportCreator::can_not_read_template(&location),
+                        ),
+                    }
                 }
                 if g.data
                     .bindings
@@ -98,8 +178,8 @@ impl Athalar {
                     reporter.add_generator_binding_report(
                         b,
                         GeneratorReportCreator::file_conflict(
-                            b.id.to_string().as_str(),
-                            b.id.to_string().as_str(),
+                            &g.name,
+                            &b.output(generator_dir).to_string_lossy(),
                         ),
                     );
                 }
@@ -132,17 +212,72 @@ impl Athalar {
                     .iter()
                     .any(|ip| ip.name == c.name && ip.id != c.id)
                 {
-                    reporter
-                        .add_partial_config_report(c, PartialReportCreator::name_conflict(&p.name));
+                    reporter.add_partial_config_report(
+                        c,
+                        PartialReportCreator::name_conflict(&p.name, &c.name),
+                    );
+                }
+            });
+        });
+    }
+
+    fn set_values_errors<'a>(&'a self, reporter: &mut ValidationReport<'a>) {
+        // only the atoms that are included by a generator need values, each of them once
+        let mut atoms: Vec<&AthalarAtom> = vec![];
+        let included = self
+            .generators
+            .iter()
+            .flat_map(|g| g.data.config.iter())
+            .filter_map(|config| match config {
+                AthalarGeneratorContent::IncludePartial(name) => {
+                    self.partials.iter().find(|p| &p.name == name)
+                }
+            })
+            .flat_map(|p| p.data.config.iter());
+        for atom in included {
+            if !atoms.iter().any(|a| a.name == atom.name) {
+                atoms.push(atom);
+            }
+        }
+        self.values.iter().for_each(|v| {
+            v.data.values.iter().for_each(|(key, value)| {
+                let atom = match atoms.iter().find(|a| &a.name == key) {
+                    Some(atom) => atom,
+                    None => {
+                        reporter
+                            .add_values_report(v, ValuesReportCreator::unknown_key(&v.name, key));
+                        return;
+                    }
+                };
+                // values that are read from the environment can only be checked once resolved
+                if let AthalarValue::Plain(value) = value {
+                    if atom.secret {
+                        reporter.add_values_report(
+                            v,
+                            ValuesReportCreator::plain_text_secret(&v.name, key),
+                        );
+                    } else if let Err(e) = atom.validate_value(value) {
+                        reporter.add_values_report(
+                            v,
+                            ValuesReportCreator::invalid_value(&v.name, key, &e.to_string()),
+                        );
+                    }
                 }
             });
+            atoms
+                .iter()
+                .filter(|a| a.is_required() && !v.data.values.iter().any(|(k, _)| k == &a.name))
+                .for_each(|a| {
+                    reporter
+                        .add_values_report(v, ValuesReportCreator::missing_value(&v.name, &a.name));
+                });
         });
     }
 
     /// Get an information table that can be used to generate bindings. This method _might_
     /// fail if there are any [severe](ReportLevel::Severe) errors. Ideally it should be
     /// called only after the report have been taken care of.
-    pub fn get_information(&self) -> Result<AthalarInformation, String> {
+    pub fn get_information(&self) -> Result<AthalarInformation<'_>, String> {
         let mut info: Vec<(&AthalarGenerator, Vec<AthalarAtom>)> = vec![];
         for generator in self.generators.iter() {
             let mut partials = vec![];
@@ -165,6 +300,83 @@ impl Athalar {
             config: &self.config,
         })
     }
+
+    /// Checks the values (usually the environment of an application) against the atoms of
+    /// the generator with this name. A value that is set using a deprecated name of an
+    /// atom is accepted, unless the current name is set as well. This fails if the
+    /// generator does not exist or its information can not be collected.
+    pub fn validate_values(
+        &self,
+        generator_name: &str,
+        values: &HashMap<String, String>,
+    ) -> Result<ValidatedValues, String> {
+        let information = self.get_information()?;
+        let (_, atoms) = information
+            .generators
+            .iter()
+            .find(|(generator, _)| generator.name == generator_name)
+            .ok_or_else(|| format!("Could not find generator: {:?}", generator_name))?;
+        let mut validated = ValidatedValues::default();
+        for atom in atoms {
+            let deprecated = atom
+                .deprecated_names
+                .iter()
+                .filter(|name| values.contains_key(*name))
+                .collect::<Vec<_>>();
+            for name in deprecated.iter() {
+                validated
+                    .reports
+                    .push(EnvironmentReportCreator::deprecated_name(name, &atom.name));
+            }
+            let value = values
+                .get(&atom.name)
+                .or_else(|| deprecated.first().map(|name| &values[*name]))
+                .or(atom.default_value.as_ref());
+            match value {
+                Some(value) => match atom.validate_value(value) {
+                    Ok(typed) => {
+                        validated.values.insert(atom.name.clone(), typed);
+                    }
+                    Err(e) => validated
+                        .reports
+                        .push(EnvironmentReportCreator::invalid_value(
+                            &atom.name,
+                            &e.to_string(),
+                        )),
+                },
+                None if atom.is_required() => validated
+                    .reports
+                    .push(EnvironmentReportCreator::missing_value(&atom.name)),
+                None => {}
+            }
+        }
+        Ok(validated)
+    }
+
+    /// Resolves the values of the environment with this name, reading the ones that are
+    /// assigned with `!FromEnv` from `env` (usually the environment of the process). Those
+    /// that are not present in `env` are left out, the result can be checked using
+    /// [this](Self::validate_values) method.
+    pub fn resolve_values(
+        &self,
+        environment: &str,
+        env: &HashMap<String, String>,
+    ) -> Result<HashMap<String, String>, String> {
+        let values = self
+            .values
+            .iter()
+            .find(|v| v.name == environment)
+            .ok_or_else(|| format!("Could not find values for environment: {:?}", environment))?;
+        Ok(values
+            .data
+            .values
+            .iter()
+            .filter_map(|(key, value)| match value {
+                AthalarValue::Plain(value) => Some((key.clone(), value.clone())),
+                AthalarValue::FromEnv(name) => env.get(name).map(|v| (key.clone(), v.clone())),
+            })
+            .collect())
+    }
 }
 
 /// This will contain all the structured information that will be needed to generate
@@ -174,3 +386,114 @@ pub struct AthalarInformation<'a> {
     pub generators: Vec<(&'a AthalarGenerator, Vec<AthalarAtom>)>,
     pub config: &'a AthalarConfig,
 }
+
+#[cfg(test)]
+mod test {
+    use crate::utils::from_path;
+    use std::{env, fs, path::PathBuf};
+
+    // a project with the mail partial of the example project and a generator with the given
+    // bindings
+    fn project(name: &str, bindings: &str) -> PathBuf {
+        let directory = env::temp_dir().join(name);
+        fs::remove_dir_all(&directory).ok();
+        fs::create_dir_all(directory.join("src/partials")).unwrap();
+        fs::create_dir_all(directory.join("src/generators")).unwrap();
+        fs::write(directory.join("athalar.toml"), "version = \"1\"\n").unwrap();
+        fs::copy(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../../apps/config/src/partials/mail.ath.yaml"),
+            directory.join("src/partials/mail.ath.yaml"),
+        )
+        .unwrap();
+        fs::write(
+            directory.join("src/generators/app.ath.yaml"),
+            format!("bindings:\n{}config:\n  - !IncludePartial mail\n", bindings),
+        )
+        .unwrap();
+        directory
+    }
+
+    fn get_codes(athalar: &crate::Athalar, read_only: bool) -> Vec<&'static str> {
+        let report = match read_only {
+            true => athalar.get_read_only_validation_report(),
+            false => athalar.get_validation_report(),
+        };
+        report
+            .generators
+            .bindings
+            .iter()
+            .map(|(_, message)| message.code)
+            .collect()
+    }
+
+    #[test]
+    fn read_only_validation_does_not_create_outputs() {
+        let project = project(
+            "athalar_core_read_only_test",
+            "  - output: app.rs\n    profile: !Rust {}\n  - output: missing/app.go\n    profile: !Go {}\n",
+        );
+        let athalar = from_path(project.to_string_lossy().to_string()).unwrap();
+        assert_eq!(get_codes(&athalar, true), vec!["CanNotCreateFile"]);
+        assert!(!project.join("app.rs").exists());
+        assert_eq!(get_codes(&athalar, false), vec!["CanNotCreateFile"]);
+        assert!(!project.join("app.rs").exists());
+    }
+
+    #[test]
+    fn templates_outside_of_the_project_are_reported() {
+        let project = project(
+            "athalar_core_template_test",
+            "  - output: app.sh\n    profile: !Template\n      path: /etc/app.tera\n",
+        );
+        let athalar = from_path(project.to_string_lossy().to_string()).unwrap();
+        assert_eq!(get_codes(&athalar, false), vec!["InvalidTemplate"]);
+    }
+
+    #[test]
+    fn values_are_validated_with_the_project() {
+        let project = project(
+            "athalar_core_values_test",
+            "  - output: app.rs\n    profile: !Rust {}\n",
+        );
+        let partial = project.join("src/partials/mail.ath.yaml");
+        let contents = fs::read_to_string(&partial).unwrap()
+            + "  - name: MAIL_PASSWORD\n    kind: !String\n    secret: true\n";
+        fs::write(&partial, contents).unwrap();
+        // the atoms of partials that no generator includes do not need values
+        fs::write(
+            project.join("src/partials/unused.ath.yaml"),
+            "config:\n  - name: UNUSED_HOST\n    kind: !String\n",
+        )
+        .unwrap();
+        let values = project.join("src/values");
+        fs::create_dir_all(&values).unwrap();
+        fs::write(
+            values.join("production.ath.yaml"),
+            "values:\n  MAIL_PORT: http\n  MAIL_HOST: smtp.example.com\n  MAIL_PASSWORD: hunter2\n  MAIL_DEBUG: true\n",
+        )
+        .unwrap();
+        fs::write(
+            values.join("staging.ath.yaml"),
+            "values:\n  MAIL_PORT: 25\n  MAIL_PASSWORD: !FromEnv STAGING_MAIL_PASSWORD\n",
+        )
+        .unwrap();
+        let athalar = from_path(project.to_string_lossy().to_string()).unwrap();
+        let report = athalar.get_read_only_validation_report();
+        let codes = report
+            .values
+            .config
+            .iter()
+            .map(|(values, message)| (values.name.as_str(), message.code))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                ("production", "InvalidValue"),
+                ("production", "PlainTextSecret"),
+                ("production", "UnknownKey"),
+                ("staging", "MissingValue"),
+            ]
+        );
+    }
+}
@@ -1,17 +1,17 @@
 use crate::{
     binding::{AthalarBinding, AthalarBindingBuilder},
-    utils::get_name_from_path,
+    utils::{get_name_from_path, get_seed, get_uuid},
 };
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 /// Contains information about a discovered generator in the project.
 #[derive(Debug, PartialEq, Builder, Clone, Eq)]
 pub struct AthalarGenerator {
-    /// A unique ID assigned to this atom, should be used as an identifier
-    #[builder(setter(skip), default = "Uuid::new_v4()")]
+    /// A unique ID assigned to this generator, derived from its path in the project
+    #[builder(setter(custom))]
     pub(crate) id: Uuid,
 
     /// The name of this generator, based on the file name.
@@ -26,6 +26,13 @@ pub struct AthalarGenerator {
 }
 
 impl AthalarGeneratorBuilder {
+    /// Derives the ID from the path of the file relative to the project root, so that it
+    /// does not depend on where the project is checked out.
+    pub(crate) fn id(&mut self, root: &Path, source: &Path) -> &mut Self {
+        self.id = Some(get_uuid(&Uuid::NAMESPACE_URL, &get_seed(root, source)));
+        self
+    }
+
     fn get_name(&self) -> Result<String, String> {
         let source = get_name_from_path(&self.source.clone().unwrap());
         Ok(source)
@@ -53,11 +60,11 @@ pub struct AthalarGeneratorData {
 }
 
 impl AthalarGeneratorData {
-    pub fn partial_from_yaml_string(yaml_string: &str) -> Self {
+    pub fn partial_from_yaml_string(yaml_string: &str) -> Result<Self, String> {
         serde_yaml::from_str::<AthalarGeneratorDataBuilder>(yaml_string)
-            .unwrap()
+            .map_err(|e| e.to_string())?
             .build()
-            .unwrap()
+            .map_err(|e| e.to_string())
     }
 }
 
@@ -111,7 +118,7 @@ mod test {
             .config(vec![AthalarGeneratorContent::IncludePartial("mail".into())])
             .build()
             .unwrap();
-        match agd.config.get(0).unwrap() {
+        match agd.config.first().unwrap() {
             AthalarGeneratorContent::IncludePartial(x) => assert_eq!(x, "mail"),
         }
     }
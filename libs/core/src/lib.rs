@@ -4,13 +4,33 @@ mod config;
 mod core;
 mod generator;
 mod partial;
+mod renderer;
 mod utils;
+mod value;
+mod values;
 
 pub mod constants;
 pub mod reporting;
 
-pub use crate::atom::{AthalarAtom, AtomKind, AtomValidator};
+pub use crate::atom::{AthalarAtom, AthalarAtomBuilder, AtomKind, AtomValidator};
 pub use crate::config::AthalarConfig;
 pub use crate::core::{Athalar, AthalarInformation};
-pub use binding::{AthalarAdapter, AthalarBinding, PydanticAdapterProfile};
+pub use binding::{
+    AthalarAdapter, AthalarAdapterKind, AthalarBinding, AthalarBindingBuilder,
+    ClassValidatorAdapterProfile, ClassValidatorAdapterProfileBuilder, ComposeAdapterProfile,
+    ComposeAdapterProfileBuilder, DotenvAdapterProfile, DotenvAdapterProfileBuilder,
+    GoAdapterProfile, GoAdapterProfileBuilder, JsonSchemaAdapterProfile,
+    JsonSchemaAdapterProfileBuilder, KubernetesAdapterProfile, KubernetesAdapterProfileBuilder,
+    MarkdownAdapterProfile, MarkdownAdapterProfileBuilder, PluginAdapterProfile,
+    PluginAdapterProfileBuilder, PydanticAdapterProfile, PydanticAdapterProfileBuilder,
+    PydanticSettingsAdapterProfile, PydanticSettingsAdapterProfileBuilder, RustAdapterProfile,
+    RustAdapterProfileBuilder, TemplateAdapterProfile, TemplateAdapterProfileBuilder,
+    ZodAdapterProfile, ZodAdapterProfileBuilder,
+};
+pub use renderer::{
+    render_template, AdapterRegistry, AtomContext, FileRenderer, RenderContext, Renderer,
+    UnsupportedProfileError,
+};
 pub use utils::{from_path, FinalFile};
+pub use value::{TypedValue, ValidatedValues, ValueError};
+pub use values::{AthalarValue, AthalarValues, AthalarValuesData};
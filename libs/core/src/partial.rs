@@ -1,18 +1,18 @@
 use crate::{
     atom::{AthalarAtom, AthalarAtomBuilder},
     config::AthalarConfigKind,
-    utils::get_name_from_path,
+    utils::{get_name_from_path, get_seed, get_uuid},
 };
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 /// Contains information about a discovered partial in the project.
 #[derive(Debug, PartialEq, Eq, Builder, Clone)]
 pub struct AthalarPartial {
-    /// A unique ID assigned to this atom, should be used as an identifier
-    #[builder(setter(skip), default = "Uuid::new_v4()")]
+    /// A unique ID assigned to this partial, derived from its path in the project
+    #[builder(setter(custom))]
     pub(crate) id: Uuid,
 
     /// The name of this partial, based on the file name.
@@ -27,6 +27,13 @@ pub struct AthalarPartial {
 }
 
 impl AthalarPartialBuilder {
+    /// Derives the ID from the path of the file relative to the project root, so that it
+    /// does not depend on where the project is checked out.
+    pub(crate) fn id(&mut self, root: &Path, source: &Path) -> &mut Self {
+        self.id = Some(get_uuid(&Uuid::NAMESPACE_URL, &get_seed(root, source)));
+        self
+    }
+
     fn get_name(&self) -> Result<String, String> {
         let source = get_name_from_path(&self.source.clone().unwrap());
         Ok(source)
@@ -43,17 +50,17 @@ pub struct AthalarPartialData {
     /// The actual data in the file
     #[builder(field(
         type = "Vec<AthalarAtomBuilder>",
-        build = "self.config.iter().map(|c| c.build().unwrap()).collect()"
+        build = "self.config.iter().map(|c| c.build()).collect::<Result<_, _>>().map_err(|e| e.to_string())?"
     ))]
     pub config: Vec<AthalarAtom>,
 }
 
 impl AthalarPartialData {
-    pub fn partial_from_yaml_string(yaml_string: &str) -> Self {
+    pub fn partial_from_yaml_string(yaml_string: &str) -> Result<Self, String> {
         serde_yaml::from_str::<AthalarPartialDataBuilder>(yaml_string)
-            .unwrap()
+            .map_err(|e| e.to_string())?
             .build()
-            .unwrap()
+            .map_err(|e| e.to_string())
     }
 }
 
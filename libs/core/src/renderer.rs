@@ -0,0 +1,332 @@
+//! This module contains the interface that adapters implement to turn a binding into
+//! files, and the registry that consuming libraries use to look them up.
+
+use crate::{
+    atom::AthalarAtom,
+    binding::{AthalarAdapterKind, AthalarBinding},
+    core::AthalarInformation,
+    reporting::{ReportLevel, ReportMessage, ValidationReport},
+    utils::FinalFile,
+};
+use serde::Serialize;
+use std::{collections::HashMap, error::Error, fmt, path::Path};
+use tera::{Context as TeraContext, Tera, Value};
+
+/// Information about where a binding is being rendered, which is not a part of the binding
+/// itself.
+#[derive(Debug)]
+pub struct RenderContext<'a> {
+    /// The name of the generator that the binding belongs to
+    pub generator_name: &'a str,
+
+    /// The directory that the paths in the project are relative to
+    pub project_source: &'a Path,
+}
+
+/// The view of an atom that is handed to the templates and plugins supplied by projects,
+/// which is kept stable across releases. The `default`, `description` and `partial` are
+/// left out when they are not set.
+#[derive(Debug, Serialize)]
+pub struct AtomContext {
+    pub name: String,
+    pub kind: String,
+    pub validators: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial: Option<String>,
+    pub optional: bool,
+    pub required: bool,
+    pub secret: bool,
+}
+
+impl From<&AthalarAtom> for AtomContext {
+    fn from(atom: &AthalarAtom) -> Self {
+        Self {
+            name: atom.name.clone(),
+            kind: atom.kind.to_string(),
+            validators: atom.validators.iter().map(|v| v.to_string()).collect(),
+            default: atom.default_value.clone(),
+            description: atom.description.clone(),
+            partial: atom.partial.clone(),
+            optional: atom.optional,
+            required: atom.is_required(),
+            secret: atom.secret,
+        }
+    }
+}
+
+/// An adapter that can render a binding, using the atoms of the generator it belongs to.
+pub trait Renderer {
+    /// Renders the binding and returns the files that should be written for it.
+    fn render(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<Vec<FinalFile>>;
+
+    /// Validates the binding and returns the problems that were found, which get merged
+    /// into the [ValidationReport]. Most renderers have nothing to add to the checks done
+    /// by the core.
+    fn validate(
+        &self,
+        _binding: &AthalarBinding,
+        _atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<Vec<ReportMessage>> {
+        Ok(vec![])
+    }
+
+    /// Renders the binding and returns the files along with the problems that
+    /// [validate](Self::validate) would find. Nothing is rendered when one of them is
+    /// severe. Renderers that find problems by doing the work of rendering (eg: running a
+    /// process) override this, so that the work is only done once.
+    fn render_with_reports(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<(Vec<FinalFile>, Vec<ReportMessage>)> {
+        let reports = self.validate(binding, atoms, context)?;
+        if reports.iter().any(|r| r.level == ReportLevel::Severe) {
+            return Ok((vec![], reports));
+        }
+        Ok((self.render(binding, atoms, context)?, reports))
+    }
+}
+
+/// An adapter that renders a binding to a single file, which is written to the output of
+/// the binding. Every type that implements this is also a [Renderer].
+pub trait FileRenderer {
+    /// Renders the contents of the file for the binding.
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<String>;
+}
+
+impl<T: FileRenderer> Renderer for T {
+    fn render(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<Vec<FinalFile>> {
+        Ok(vec![FinalFile {
+            path: binding.output(context.project_source),
+            contents: self.render_contents(binding, atoms, context)?,
+        }])
+    }
+}
+
+/// A tera filter that puts a comment marker in front of every line of a description, so
+/// that a description spanning several lines stays a comment in the generated file. The
+/// marker (along with the indentation) is given as `prefix` and defaults to `# `.
+fn comment(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let text = tera::try_get_value!("comment", "value", String, value);
+    let prefix = match args.get("prefix") {
+        Some(prefix) => tera::try_get_value!("comment", "prefix", String, prefix),
+        None => "# ".to_string(),
+    };
+    let lines = text
+        .lines()
+        .map(|line| format!("{}{}", prefix, line).trim_end().to_string())
+        .collect::<Vec<_>>();
+    Ok(Value::String(lines.join("\n")))
+}
+
+/// Renders a template of an adapter along with the filters that are shared by all of them,
+/// eg: `{{ property.comment | comment(prefix="/// ") }}`.
+pub fn render_template(template: &str, context: &TeraContext) -> tera::Result<String> {
+    let mut tera = Tera::default();
+    tera.register_filter("comment", comment);
+    tera.add_raw_template("template", template)?;
+    tera.render("template", context)
+}
+
+/// Returned by a renderer when it is asked to render a binding with a profile that it
+/// does not support.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnsupportedProfileError(pub AthalarAdapterKind);
+
+impl fmt::Display for UnsupportedProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The {} profile is not supported by this renderer",
+            self.0
+        )
+    }
+}
+
+impl Error for UnsupportedProfileError {}
+
+/// Maps the different [kinds](AthalarAdapterKind) of adapters to the renderer that should
+/// be used for them.
+#[derive(Default)]
+pub struct AdapterRegistry {
+    renderers: HashMap<AthalarAdapterKind, Box<dyn Renderer>>,
+}
+
+impl AdapterRegistry {
+    /// Registers the renderer for a kind of adapter, replacing the one that was registered
+    /// earlier (if any).
+    pub fn register(&mut self, kind: AthalarAdapterKind, renderer: impl Renderer + 'static) {
+        self.renderers.insert(kind, Box::new(renderer));
+    }
+
+    /// Get the renderer registered for a kind of adapter.
+    pub fn get(&self, kind: AthalarAdapterKind) -> Option<&dyn Renderer> {
+        self.renderers.get(&kind).map(|r| r.as_ref())
+    }
+
+    // the renderer registered for the profile of a binding
+    fn get_renderer(&self, binding: &AthalarBinding) -> anyhow::Result<&dyn Renderer> {
+        let kind = AthalarAdapterKind::from(&binding.profile);
+        match self.get(kind) {
+            Some(renderer) => Ok(renderer),
+            None => anyhow::bail!("No renderer has been registered for the {} profile", kind),
+        }
+    }
+
+    /// Renders a single binding using the renderer registered for its profile.
+    pub fn render_binding(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<Vec<FinalFile>> {
+        self.get_renderer(binding)?.render(binding, atoms, context)
+    }
+
+    /// Renders all the bindings in the information table, in the order of the generators
+    /// they belong to.
+    pub fn render(&self, information: &AthalarInformation) -> anyhow::Result<Vec<FinalFile>> {
+        let project_source = information.config.project_source();
+        let mut final_files = vec![];
+        for (generator, atoms) in information.generators.iter() {
+            let context = RenderContext {
+                generator_name: &generator.name,
+                project_source: &project_source,
+            };
+            for binding in generator.data.bindings.iter() {
+                final_files.extend(self.render_binding(binding, atoms, &context)?);
+            }
+        }
+        Ok(final_files)
+    }
+
+    /// Renders all the bindings in the information table like [render](Self::render), and
+    /// adds the problems that the renderers find to the report. The files of the bindings
+    /// with severe problems are left out.
+    pub fn render_with_reports<'a>(
+        &self,
+        information: &AthalarInformation<'a>,
+        reporter: &mut ValidationReport<'a>,
+    ) -> anyhow::Result<Vec<FinalFile>> {
+        let project_source = information.config.project_source();
+        let mut final_files = vec![];
+        for (generator, atoms) in information.generators.iter() {
+            let context = RenderContext {
+                generator_name: &generator.name,
+                project_source: &project_source,
+            };
+            for binding in generator.data.bindings.iter() {
+                let (files, reports) = self
+                    .get_renderer(binding)?
+                    .render_with_reports(binding, atoms, &context)?;
+                final_files.extend(files);
+                for report in reports {
+                    reporter.add_generator_binding_report(binding, report);
+                }
+            }
+        }
+        Ok(final_files)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binding::{
+        AthalarAdapter, AthalarBindingBuilder, RustAdapterProfileBuilder, ZodAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    struct NameRenderer;
+
+    impl FileRenderer for NameRenderer {
+        fn render_contents(
+            &self,
+            _binding: &AthalarBinding,
+            atoms: &[AthalarAtom],
+            context: &RenderContext,
+        ) -> anyhow::Result<String> {
+            Ok(format!("{}: {}", context.generator_name, atoms.len()))
+        }
+    }
+
+    fn context() -> RenderContext<'static> {
+        RenderContext {
+            generator_name: "backend",
+            project_source: Path::new("/project"),
+        }
+    }
+
+    #[test]
+    fn every_line_of_a_comment_is_prefixed() {
+        let mut context = TeraContext::new();
+        context.insert("comment", "The port\n\nof the server");
+        assert_eq!(
+            render_template("{{ comment | comment }}", &context).unwrap(),
+            "# The port\n#\n# of the server"
+        );
+        assert_eq!(
+            render_template("{{ comment | comment(prefix=\"    /// \") }}", &context).unwrap(),
+            "    /// The port\n    ///\n    /// of the server"
+        );
+    }
+
+    #[test]
+    fn renders_with_registered_renderer() {
+        let mut registry = AdapterRegistry::default();
+        registry.register(AthalarAdapterKind::Rust, NameRenderer);
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("src/config.rs"))
+            .profile(AthalarAdapter::Rust(
+                RustAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let files = registry.render_binding(&binding, &[], &context()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, PathBuf::from("/project/src/config.rs"));
+        assert_eq!(files[0].contents, "backend: 0");
+    }
+
+    #[test]
+    fn unregistered_profile_is_an_error() {
+        let mut registry = AdapterRegistry::default();
+        registry.register(AthalarAdapterKind::Rust, NameRenderer);
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("src/config.ts"))
+            .profile(AthalarAdapter::Zod(
+                ZodAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let error = registry
+            .render_binding(&binding, &[], &context())
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "No renderer has been registered for the Zod profile"
+        );
+    }
+}
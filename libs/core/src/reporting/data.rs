@@ -21,18 +21,45 @@ impl GeneratorReportCreator {
         }
     }
 
-    pub fn file_conflict(b1: &str, b2: &str) -> ReportMessage {
+    pub fn file_conflict(generator_name: &str, location: &str) -> ReportMessage {
         ReportMessage {
             origin: ReportMessageOrigin::GeneratorBinding,
             code: "FileConflict",
             level: ReportLevel::Warning,
             message: format!(
-                "Generator binding {:?} and {:?} have the same output location",
-                b1, b2
+                "Generator {:?} has more than one binding with the output location {:?}",
+                generator_name, location
             ),
         }
     }
 
+    pub fn can_not_read_template(location: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::GeneratorBinding,
+            code: "CanNotReadTemplate",
+            level: ReportLevel::Severe,
+            message: format!("Template at location {:?} can not be read", location),
+        }
+    }
+
+    pub fn invalid_template(location: &str, reason: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::GeneratorBinding,
+            code: "InvalidTemplate",
+            level: ReportLevel::Severe,
+            message: format!("Template at location {:?} is invalid: {}", location, reason),
+        }
+    }
+
+    pub fn plugin_diagnostic(command: &str, level: ReportLevel, message: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::GeneratorBinding,
+            code: "PluginDiagnostic",
+            level,
+            message: format!("Plugin {:?} reported: {}", command, message),
+        }
+    }
+
     pub fn partial_does_not_exist(partial_name: &str) -> ReportMessage {
         ReportMessage {
             origin: ReportMessageOrigin::GeneratorConfig,
@@ -46,14 +73,112 @@ impl GeneratorReportCreator {
 pub struct PartialReportCreator {}
 
 impl PartialReportCreator {
-    pub fn name_conflict(partial_name: &str) -> ReportMessage {
+    pub fn name_conflict(partial_name: &str, atom_name: &str) -> ReportMessage {
         ReportMessage {
             origin: ReportMessageOrigin::PartialConfig,
             code: "NameConflict",
             level: ReportLevel::Warning,
             message: format!(
-                "Partial name {:?} conflicts with another partial of the same name",
-                partial_name
+                "Atom {:?} is declared more than once in partial {:?}",
+                atom_name, partial_name
+            ),
+        }
+    }
+}
+
+pub struct EnvironmentReportCreator {}
+
+impl EnvironmentReportCreator {
+    pub fn missing_value(atom_name: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Environment,
+            code: "MissingValue",
+            level: ReportLevel::Severe,
+            message: format!("Required variable {:?} is not set", atom_name),
+        }
+    }
+
+    pub fn invalid_value(atom_name: &str, reason: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Environment,
+            code: "InvalidValue",
+            level: ReportLevel::Severe,
+            message: format!("Variable {:?} has an invalid value: {}", atom_name, reason),
+        }
+    }
+
+    pub fn unknown_variable(name: &str, prefix: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Environment,
+            code: "UnknownVariable",
+            level: ReportLevel::Warning,
+            message: format!(
+                "Variable {:?} has the prefix {:?} but is not declared",
+                name, prefix
+            ),
+        }
+    }
+
+    pub fn deprecated_name(deprecated_name: &str, atom_name: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Environment,
+            code: "DeprecatedName",
+            level: ReportLevel::Warning,
+            message: format!(
+                "Variable {:?} is deprecated, it should be renamed to {:?}",
+                deprecated_name, atom_name
+            ),
+        }
+    }
+}
+
+pub struct ValuesReportCreator {}
+
+impl ValuesReportCreator {
+    pub fn unknown_key(environment: &str, key: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Values,
+            code: "UnknownKey",
+            level: ReportLevel::Warning,
+            message: format!(
+                "Variable {:?} in the {:?} values is not included by any generator",
+                key, environment
+            ),
+        }
+    }
+
+    pub fn missing_value(environment: &str, atom_name: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Values,
+            code: "MissingValue",
+            level: ReportLevel::Severe,
+            message: format!(
+                "Required variable {:?} is not set in the {:?} values",
+                atom_name, environment
+            ),
+        }
+    }
+
+    pub fn invalid_value(environment: &str, atom_name: &str, reason: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Values,
+            code: "InvalidValue",
+            level: ReportLevel::Severe,
+            message: format!(
+                "Variable {:?} has an invalid value in the {:?} values: {}",
+                atom_name, environment, reason
+            ),
+        }
+    }
+
+    pub fn plain_text_secret(environment: &str, atom_name: &str) -> ReportMessage {
+        ReportMessage {
+            origin: ReportMessageOrigin::Values,
+            code: "PlainTextSecret",
+            level: ReportLevel::Severe,
+            message: format!(
+                "Secret {:?} is written in plain text in the {:?} values, use !FromEnv instead",
+                atom_name, environment
             ),
         }
     }
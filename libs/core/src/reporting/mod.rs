@@ -1,17 +1,23 @@
 //! This module is responsible for generating reports during the validation phase of an
 //! athalar project.
 
-use crate::{atom::AthalarAtom, binding::AthalarBinding, generator::AthalarGeneratorContent};
+use crate::{
+    atom::AthalarAtom, binding::AthalarBinding, generator::AthalarGeneratorContent,
+    values::AthalarValues,
+};
+use serde::Deserialize;
 use strum_macros::Display;
 
 /// The different levels of errors that can be present in a validation report.
-#[derive(Debug, PartialEq, Eq, Display)]
+#[derive(Debug, PartialEq, Eq, Display, Deserialize)]
 pub enum ReportLevel {
     /// If this error is encountered, the generation phase is guaranteed to fail.
     Severe,
     /// If this error is encountered, the generation phase should succeed but might have
     /// unexpected results. It is recommended to not ignore them.
     Warning,
+    /// Nothing is wrong, but it might be useful to know about.
+    Info,
 }
 
 // generators
@@ -21,6 +27,8 @@ pub enum ReportMessageOrigin {
     GeneratorBinding,
     GeneratorConfig,
     PartialConfig,
+    Environment,
+    Values,
 }
 
 /// An error code combined with a message that can be displayed to the end users
@@ -54,12 +62,22 @@ pub struct PartialReport<'a> {
     pub config: Vec<(&'a AthalarAtom, ReportMessage)>,
 }
 
+// values
+
+/// This struct will validation information about the values of the environments in an
+/// athalar project.
+#[derive(Debug)]
+pub struct ValuesReport<'a> {
+    pub config: Vec<(&'a AthalarValues, ReportMessage)>,
+}
+
 /// This contains all the information about the different problems that were detected
 /// during the validation phase.
 #[derive(Debug)]
 pub struct ValidationReport<'a> {
     pub generators: GeneratorReport<'a>,
     pub partials: PartialReport<'a>,
+    pub values: ValuesReport<'a>,
 }
 
 impl<'a> ValidationReport<'a> {
@@ -69,9 +87,11 @@ impl<'a> ValidationReport<'a> {
             config: vec![],
         };
         let partials = PartialReport { config: vec![] };
+        let values = ValuesReport { config: vec![] };
         Self {
             generators,
             partials,
+            values,
         }
     }
 
@@ -98,6 +118,11 @@ impl<'a> ValidationReport<'a> {
                 return true;
             }
         }
+        for c in self.values.config.iter() {
+            if c.1.level == level {
+                return true;
+            }
+        }
         false
     }
 
@@ -120,6 +145,10 @@ impl<'a> ValidationReport<'a> {
     pub fn add_partial_config_report(&mut self, atom: &'a AthalarAtom, report: ReportMessage) {
         self.partials.config.push((atom, report));
     }
+
+    pub fn add_values_report(&mut self, values: &'a AthalarValues, report: ReportMessage) {
+        self.values.config.push((values, report));
+    }
 }
 
 impl<'a> Default for ValidationReport<'a> {
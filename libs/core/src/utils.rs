@@ -4,6 +4,7 @@ use crate::{
     constants::ATHALAR_CONFIG_FILE,
     generator::{AthalarGenerator, AthalarGeneratorBuilder, AthalarGeneratorData},
     partial::{AthalarPartial, AthalarPartialBuilder, AthalarPartialData},
+    values::{AthalarValues, AthalarValuesBuilder, AthalarValuesData},
     Athalar, AthalarConfig,
 };
 use glob::glob;
@@ -17,9 +18,8 @@ use uuid::Uuid;
 pub fn get_name_from_path(path: &Path) -> String {
     path.to_str()
         .unwrap()
-        .to_string()
         .split(MAIN_SEPARATOR)
-        .last()
+        .next_back()
         .unwrap()
         .split('.')
         .collect::<Vec<_>>()
@@ -28,55 +28,103 @@ pub fn get_name_from_path(path: &Path) -> String {
         .to_string()
 }
 
-fn get_file_source_and_contents(dir: &Path) -> Vec<(PathBuf, String)> {
+fn get_file_source_and_contents(dir: &Path) -> Result<Vec<(PathBuf, String)>, String> {
     let glob_pattern = dir
         .join("*.ath.yaml")
         .into_os_string()
         .into_string()
         .unwrap();
-    glob(&glob_pattern)
+    let mut paths = glob(&glob_pattern)
         .expect("Failed to read glob pattern")
         .flatten()
-        .map(|p| {
-            let yaml_string = fs::read_to_string(&p).unwrap();
-            (p, yaml_string)
+        .collect::<Vec<_>>();
+    // the order in which files are discovered decides the order of the generated output,
+    // so it should not depend on the platform
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|p| match fs::read_to_string(&p) {
+            Ok(yaml_string) => Ok((p, yaml_string)),
+            Err(e) => Err(format!("Unable to read {:?}: {}", p, e)),
         })
         .collect()
 }
 
 /// Load all the partials from a given path using globs
-pub fn load_partials(dir: &Path) -> Vec<AthalarPartial> {
-    get_file_source_and_contents(dir)
+pub fn load_partials(dir: &Path, root: &Path) -> Result<Vec<AthalarPartial>, String> {
+    get_file_source_and_contents(dir)?
         .into_iter()
         .map(|(path, contents)| {
-            let apd = AthalarPartialData::partial_from_yaml_string(&contents);
-            AthalarPartialBuilder::default()
+            let apd = AthalarPartialData::partial_from_yaml_string(&contents)
+                .map_err(|e| format!("Unable to parse {:?}: {}", path, e))?;
+            let mut partial = AthalarPartialBuilder::default()
+                .id(root, &path)
                 .source(path)
                 .data(apd)
                 .build()
-                .unwrap()
+                .unwrap();
+            for (idx, atom) in partial.data.config.iter_mut().enumerate() {
+                atom.id = get_uuid(&partial.id, &format!("config/{}", idx));
+                atom.partial = Some(partial.name.clone());
+            }
+            Ok(partial)
         })
         .collect()
 }
 
 /// Load all the generators from a given path using globs
-pub fn load_generators(dir: &Path) -> Vec<AthalarGenerator> {
-    get_file_source_and_contents(dir)
+pub fn load_generators(dir: &Path, root: &Path) -> Result<Vec<AthalarGenerator>, String> {
+    get_file_source_and_contents(dir)?
         .into_iter()
         .map(|(path, contents)| {
-            let apd = AthalarGeneratorData::partial_from_yaml_string(&contents);
-            AthalarGeneratorBuilder::default()
+            let apd = AthalarGeneratorData::partial_from_yaml_string(&contents)
+                .map_err(|e| format!("Unable to parse {:?}: {}", path, e))?;
+            let mut generator = AthalarGeneratorBuilder::default()
+                .id(root, &path)
                 .source(path)
                 .data(apd)
                 .build()
-                .unwrap()
+                .unwrap();
+            for (idx, binding) in generator.data.bindings.iter_mut().enumerate() {
+                binding.id = get_uuid(&generator.id, &format!("bindings/{}", idx));
+            }
+            Ok(generator)
+        })
+        .collect()
+}
+
+/// Load the values of all the environments from a given path using globs
+pub fn load_values(dir: &Path, root: &Path) -> Result<Vec<AthalarValues>, String> {
+    get_file_source_and_contents(dir)?
+        .into_iter()
+        .map(|(path, contents)| {
+            let avd = AthalarValuesData::values_from_yaml_string(&contents)
+                .map_err(|e| format!("Unable to parse {:?}: {}", path, e))?;
+            Ok(AthalarValuesBuilder::default()
+                .id(root, &path)
+                .source(path)
+                .data(avd)
+                .build()
+                .unwrap())
         })
         .collect()
 }
 
-/// Generate a new random UUID
-pub(crate) fn get_uuid() -> Uuid {
-    Uuid::new_v4()
+/// The path of a project file relative to the project root, with the same separator on
+/// all platforms. It is used to derive IDs that do not depend on where the project is.
+pub(crate) fn get_seed(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Derive a UUID from a namespace and a seed. The same inputs always produce the same
+/// UUID, which keeps reports and generated output reproducible across runs.
+pub(crate) fn get_uuid(namespace: &Uuid, seed: &str) -> Uuid {
+    Uuid::new_v5(namespace, seed.as_bytes())
 }
 
 /// Get the configuration and all the required information about an athalar project.
@@ -91,9 +139,9 @@ pub fn from_path(path: String) -> Result<Athalar, String> {
             ));
         }
     };
-    let config = AthalarConfig::from_str_and_source(&config_file_contents, &path).unwrap();
-    let athalar = Athalar::from_config(config);
-    Ok(athalar)
+    let config = AthalarConfig::from_str_and_source(&config_file_contents, &path)
+        .map_err(|e| format!("Unable to parse {:?}: {}", &project_path, e))?;
+    Athalar::from_config(config)
 }
 
 /// The final rendered file output that should be placed in the file system.
@@ -104,3 +152,89 @@ pub struct FinalFile {
     /// The contents of the file
     pub contents: String,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    fn example_project() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../apps/config")
+    }
+
+    fn example_partials() -> Vec<AthalarPartial> {
+        let project = example_project();
+        load_partials(&project.join("src/partials"), &project).unwrap()
+    }
+
+    #[test]
+    fn same_seed_gives_same_uuid() {
+        let first = get_uuid(&Uuid::NAMESPACE_URL, "src/partials/mail.ath.yaml");
+        let second = get_uuid(&Uuid::NAMESPACE_URL, "src/partials/mail.ath.yaml");
+        assert_eq!(first, second);
+        let other = get_uuid(&Uuid::NAMESPACE_URL, "src/partials/api.ath.yaml");
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn partials_are_loaded_in_sorted_order() {
+        let partials = example_partials();
+        let names = partials.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["api", "magic", "mail"]);
+    }
+
+    #[test]
+    fn loading_twice_gives_same_ids() {
+        let first = example_partials();
+        let second = example_partials();
+        assert_eq!(first, second);
+        let mail = first.iter().find(|p| p.name == "mail").unwrap();
+        assert_ne!(mail.data.config[0].id, mail.data.config[1].id);
+    }
+
+    #[test]
+    fn ids_do_not_depend_on_the_location_of_the_project() {
+        let copy = env::temp_dir().join("athalar_core_ids_test");
+        fs::remove_dir_all(&copy).ok();
+        fs::create_dir_all(copy.join("src/partials")).unwrap();
+        let source = example_project().join("src/partials");
+        for entry in fs::read_dir(&source).unwrap().flatten() {
+            fs::copy(
+                entry.path(),
+                copy.join("src/partials").join(entry.file_name()),
+            )
+            .unwrap();
+        }
+        let copied = load_partials(&copy.join("src/partials"), &copy).unwrap();
+        let ids = |partials: &[AthalarPartial]| partials.iter().map(|p| p.id).collect::<Vec<_>>();
+        assert_eq!(ids(&copied), ids(&example_partials()));
+        assert_eq!(
+            get_seed(&copy, &copy.join("src/partials/mail.ath.yaml")),
+            "src/partials/mail.ath.yaml"
+        );
+    }
+
+    #[test]
+    fn files_that_can_not_be_parsed_are_errors() {
+        let project = env::temp_dir().join("athalar_core_parse_test");
+        fs::remove_dir_all(&project).ok();
+        fs::create_dir_all(project.join("src/partials")).unwrap();
+        fs::write(project.join("athalar.toml"), "version = \"1\"\n").unwrap();
+        let partial = project.join("src/partials/mail.ath.yaml");
+        let load = || from_path(project.to_string_lossy().to_string());
+        fs::write(&partial, "config:\n  - name: MAIL_PORT\n").unwrap();
+        let error = load().unwrap_err();
+        assert!(error.contains("mail.ath.yaml"), "{}", error);
+        assert!(error.ends_with("\"MAIL_PORT\" needs a kind or at least one validator"));
+        fs::write(&partial, "config: [").unwrap();
+        assert!(load().unwrap_err().starts_with("Unable to parse"));
+        fs::write(
+            &partial,
+            "config:\n  - name: MAIL_PORT\n    kind: !Number\n",
+        )
+        .unwrap();
+        assert!(load().is_ok());
+        fs::write(project.join("athalar.toml"), "version = \"2\"\n").unwrap();
+        assert!(load().is_err());
+    }
+}
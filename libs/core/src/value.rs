@@ -0,0 +1,167 @@
+//! The canonical implementation of the [validators](AtomValidator), so that the values of
+//! configuration variables can be checked the same way by all the consuming libraries.
+
+use crate::{
+    atom::{AthalarAtom, AtomKind, AtomValidator},
+    reporting::ReportMessage,
+};
+use std::{collections::HashMap, error::Error, fmt};
+
+/// A value that passed the validators of an atom, converted to the [kind](AtomKind) of
+/// the atom. Values of atoms that can be of any kind are left as strings.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TypedValue {
+    Number(f64),
+    String(String),
+}
+
+/// The error returned when a value does not pass one of the validators of an atom.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ValueError {
+    /// The validator that the value failed
+    pub validator: AtomValidator,
+
+    /// The value as it was received
+    pub value: String,
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} does not pass the {} validator",
+            self.value, self.validator
+        )
+    }
+}
+
+impl Error for ValueError {}
+
+// a scheme (as defined by RFC 3986) followed by `://`, eg: `postgres://localhost:5432`
+fn is_url(value: &str) -> bool {
+    match value.split_once("://") {
+        Some((scheme, rest)) => {
+            !rest.is_empty()
+                && !rest.contains(char::is_whitespace)
+                && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+        None => false,
+    }
+}
+
+impl AtomValidator {
+    /// Whether the value (as it would appear in the environment) passes this validator.
+    ///
+    /// - `Number`: a finite decimal number, eg: `42`, `-0.5` or `1e3`
+    /// - `Port`: an integer from `0` to `65535`
+    /// - `Url`: a scheme followed by `://` and a location without whitespace
+    /// - `String` and `Noop`: any value
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            Self::Number => value.parse::<f64>().is_ok_and(|n| n.is_finite()),
+            Self::Port => value.parse::<u16>().is_ok(),
+            Self::Url => is_url(value),
+            Self::String | Self::Noop => true,
+        }
+    }
+}
+
+impl AthalarAtom {
+    /// Checks the value against all the validators of this atom and converts it to the
+    /// kind of this atom.
+    pub fn validate_value(&self, value: &str) -> Result<TypedValue, ValueError> {
+        let error = |validator: &AtomValidator| ValueError {
+            validator: validator.clone(),
+            value: value.to_string(),
+        };
+        if let Some(validator) = self.validators.iter().find(|v| !v.is_valid(value)) {
+            return Err(error(validator));
+        }
+        match self.kind {
+            AtomKind::Number => value
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .map(TypedValue::Number)
+                .ok_or_else(|| error(&AtomValidator::Number)),
+            AtomKind::String | AtomKind::Any => Ok(TypedValue::String(value.to_string())),
+        }
+    }
+}
+
+/// The result of checking a set of values against the atoms of a generator, see
+/// [validate_values](crate::Athalar::validate_values).
+#[derive(Debug, Default)]
+pub struct ValidatedValues {
+    /// The values that passed validation (including the defaults of the atoms that were not
+    /// set), keyed by the name of the atom
+    pub values: HashMap<String, TypedValue>,
+
+    /// The values that are missing or invalid, and the ones set using a deprecated name
+    pub reports: Vec<ReportMessage>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::atom::AthalarAtomBuilder;
+
+    fn atom(validators: Vec<AtomValidator>) -> AthalarAtom {
+        AthalarAtomBuilder::default()
+            .name("VARIABLE")
+            .validators(validators)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn validators_accept_canonical_values() {
+        let cases = [
+            (AtomValidator::Number, "-0.5", true),
+            (AtomValidator::Number, "1e3", true),
+            (AtomValidator::Number, "inf", false),
+            (AtomValidator::Number, "four", false),
+            (AtomValidator::Port, "65535", true),
+            (AtomValidator::Port, "65536", false),
+            (AtomValidator::Port, "-1", false),
+            (AtomValidator::Url, "postgres://localhost:5432/app", true),
+            (AtomValidator::Url, "localhost:5432", false),
+            (AtomValidator::Url, "http://local host", false),
+            (AtomValidator::Url, "1http://localhost", false),
+            (AtomValidator::String, "", true),
+            (AtomValidator::Noop, "anything", true),
+        ];
+        for (validator, value, valid) in cases {
+            assert_eq!(
+                validator.is_valid(value),
+                valid,
+                "{} {:?}",
+                validator,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn values_are_converted_to_the_kind_of_the_atom() {
+        assert_eq!(
+            atom(vec![AtomValidator::Port]).validate_value("8000"),
+            Ok(TypedValue::Number(8000.0))
+        );
+        assert_eq!(
+            atom(vec![AtomValidator::Url]).validate_value("https://example.com"),
+            Ok(TypedValue::String("https://example.com".to_string()))
+        );
+        let error = atom(vec![AtomValidator::Port])
+            .validate_value("http")
+            .unwrap_err();
+        assert_eq!(error.validator, AtomValidator::Port);
+        assert_eq!(
+            error.to_string(),
+            "\"http\" does not pass the Port validator"
+        );
+    }
+}
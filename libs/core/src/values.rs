@@ -0,0 +1,137 @@
+use crate::utils::{get_name_from_path, get_seed, get_uuid};
+use derive_builder::Builder;
+use serde::{Deserialize, Deserializer};
+use serde_yaml::Value;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// A value that is assigned to a configuration variable in an environment.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AthalarValue {
+    /// A value that is written in the file (eg: `APP_PORT: 8000`). It is stored the way it
+    /// would appear in the environment.
+    Plain(String),
+
+    /// The name of a variable that is read from the environment when the values are
+    /// resolved (eg: `DATABASE_URL: !FromEnv STAGING_DATABASE_URL`). Secrets must always be
+    /// assigned this way.
+    FromEnv(String),
+}
+
+impl<'de> Deserialize<'de> for AthalarValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(s) => Ok(Self::Plain(s)),
+            Value::Number(n) => Ok(Self::Plain(n.to_string())),
+            Value::Bool(b) => Ok(Self::Plain(b.to_string())),
+            Value::Tagged(tagged) if tagged.tag == "FromEnv" => match tagged.value {
+                Value::String(name) => Ok(Self::FromEnv(name)),
+                _ => Err(serde::de::Error::custom(
+                    "!FromEnv must be followed by the name of a variable",
+                )),
+            },
+            _ => Err(serde::de::Error::custom(
+                "a value must be a scalar or a !FromEnv reference",
+            )),
+        }
+    }
+}
+
+/// Contains the values that are assigned to the configuration variables in an environment
+/// (eg: `staging`).
+#[derive(Debug, PartialEq, Eq, Builder, Clone)]
+pub struct AthalarValues {
+    /// A unique ID assigned to these values, derived from their path in the project
+    #[builder(setter(custom))]
+    pub(crate) id: Uuid,
+
+    /// The name of the environment, based on the file name.
+    #[builder(default = "self.get_name()?")]
+    pub name: String,
+
+    /// The path to this file relative to the current directory
+    source: PathBuf,
+
+    /// The actual data that is in this values file
+    pub data: AthalarValuesData,
+}
+
+impl AthalarValuesBuilder {
+    /// Derives the ID from the path of the file relative to the project root, so that it
+    /// does not depend on where the project is checked out.
+    pub(crate) fn id(&mut self, root: &Path, source: &Path) -> &mut Self {
+        self.id = Some(get_uuid(&Uuid::NAMESPACE_URL, &get_seed(root, source)));
+        self
+    }
+
+    fn get_name(&self) -> Result<String, String> {
+        let source = get_name_from_path(&self.source.clone().unwrap());
+        Ok(source)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize)]
+pub struct AthalarValuesData {
+    /// The values keyed by the name of the configuration variable, in the order they were
+    /// written
+    #[serde(default, deserialize_with = "deserialize_values")]
+    pub values: Vec<(String, AthalarValue)>,
+}
+
+// a mapping is used (instead of a `HashMap`) so that reports follow the order of the file
+fn deserialize_values<'de, D>(deserializer: D) -> Result<Vec<(String, AthalarValue)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mapping = Option::<serde_yaml::Mapping>::deserialize(deserializer)?.unwrap_or_default();
+    mapping
+        .into_iter()
+        .map(|(key, value)| {
+            let key = match key {
+                Value::String(key) => key,
+                _ => return Err(serde::de::Error::custom("keys must be variable names")),
+            };
+            let value = AthalarValue::deserialize(value).map_err(serde::de::Error::custom)?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+impl AthalarValuesData {
+    pub fn values_from_yaml_string(yaml_string: &str) -> Result<Self, String> {
+        serde_yaml::from_str::<Self>(yaml_string).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn values_are_read_in_order() {
+        let avd = AthalarValuesData::values_from_yaml_string(
+            "values:\n  APP_PORT: 8000\n  DATABASE_URL: !FromEnv STAGING_DATABASE_URL\n  DEBUG: false\n",
+        )
+        .unwrap();
+        assert_eq!(
+            avd.values,
+            vec![
+                (
+                    "APP_PORT".to_string(),
+                    AthalarValue::Plain("8000".to_string())
+                ),
+                (
+                    "DATABASE_URL".to_string(),
+                    AthalarValue::FromEnv("STAGING_DATABASE_URL".to_string())
+                ),
+                (
+                    "DEBUG".to_string(),
+                    AthalarValue::Plain("false".to_string())
+                ),
+            ]
+        );
+    }
+}
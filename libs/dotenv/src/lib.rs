@@ -0,0 +1,154 @@
+use athalar_core::{
+    render_template, AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde::{Deserialize, Serialize};
+use tera::Context as TeraContext;
+
+const DOTENV_TEMPLATE: &str = include_str!("dotenv.tera");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PropertyContext {
+    name: String,
+    value: String,
+    commented: bool,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Context {
+    properties: Vec<PropertyContext>,
+}
+
+/// Quotes a value so that dotenv parsers read it back unchanged.
+pub fn quote_value(value: &str) -> String {
+    let is_plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./:@,+%".contains(c));
+    if is_plain {
+        value.to_string()
+    } else if !value.contains('\'') && !value.contains('\n') {
+        // single quotes are not interpolated or unescaped by any dotenv parser
+        format!("'{}'", value)
+    } else {
+        format!(
+            "\"{}\"",
+            value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('$', "\\$")
+                .replace('\n', "\\n")
+        )
+    }
+}
+
+pub fn get_dotenv_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let comment_optional = match &binding.profile {
+        AthalarAdapter::Dotenv(x) => x.comment_optional.unwrap_or(true),
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let mut context = Context { properties: vec![] };
+    for atom in atoms {
+        // secrets never get their default written, they must be filled in by the user
+        let value = match (&atom.default_value, atom.secret) {
+            (Some(default_value), false) => quote_value(default_value),
+            _ => String::new(),
+        };
+        context.properties.push(PropertyContext {
+            name: atom.name.clone(),
+            commented: comment_optional && atom.optional && value.is_empty(),
+            value,
+            comment: atom.description.clone(),
+        })
+    }
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = render_template(DOTENV_TEMPLATE, &context)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the dotenv profile.
+pub struct DotenvRenderer;
+
+impl FileRenderer for DotenvRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_dotenv_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, DotenvAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    fn binding() -> AthalarBinding {
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from(".env.example"))
+            .profile(AthalarAdapter::Dotenv(
+                DotenvAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn values_are_quoted_when_needed() {
+        assert_eq!(quote_value("25"), "25");
+        assert_eq!(quote_value("https://a.com/b"), "https://a.com/b");
+        assert_eq!(quote_value("hello world"), "'hello world'");
+        assert_eq!(quote_value("it's $HOME"), "\"it's \\$HOME\"");
+    }
+
+    #[test]
+    fn defaults_are_written_except_for_secrets() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .description(Some("The mail port".to_string()))
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_KEY")
+                .validators(vec![AtomValidator::String])
+                .default_value("insecure")
+                .secret(true)
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_dotenv_contents(&binding(), &atoms).unwrap();
+        assert!(contents.starts_with("# ---"));
+        assert!(
+            contents.ends_with("---\n\n# The mail port\nMAIL_PORT=25\n\nAPI_KEY=\n\n# API_URL=\n")
+        );
+    }
+
+    #[test]
+    fn every_line_of_a_description_is_commented() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .description(Some("The mail port\n\nUsually 25 or 587\n".to_string()))
+            .build()
+            .unwrap()];
+        let contents = get_dotenv_contents(&binding(), &atoms).unwrap();
+        assert!(contents.ends_with("\n# The mail port\n#\n# Usually 25 or 587\nMAIL_PORT=\n"));
+    }
+}
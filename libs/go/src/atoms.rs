@@ -0,0 +1,53 @@
+use athalar_core::{AtomKind, AtomValidator};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// The go types that an atom can have, each of which has a different parser in the
+/// generated code.
+#[derive(Debug, Display, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum AthalarGoKind {
+    #[strum(serialize = "float64")]
+    Number,
+
+    #[strum(serialize = "string")]
+    String,
+
+    #[strum(serialize = "uint16")]
+    Port,
+
+    #[strum(serialize = "*url.URL")]
+    Url,
+}
+
+impl From<AtomKind> for AthalarGoKind {
+    fn from(av: AtomKind) -> Self {
+        match av {
+            AtomKind::Number => Self::Number,
+            AtomKind::String => Self::String,
+            // environment variables are always strings, so they can be kept as is
+            AtomKind::Any => Self::String,
+        }
+    }
+}
+
+impl AthalarGoKind {
+    /// Returns the constrained type for a validator, if the validator needs one.
+    pub fn from_validator(av: &AtomValidator) -> Option<Self> {
+        match av {
+            AtomValidator::Url => Some(Self::Url),
+            AtomValidator::Port => Some(Self::Port),
+            AtomValidator::Number | AtomValidator::String | AtomValidator::Noop => None,
+        }
+    }
+
+    /// The packages that need to be imported to parse this type.
+    pub fn imports(&self) -> &'static [&'static str] {
+        match self {
+            Self::Number => &["math", "strconv"],
+            Self::Port => &["strconv"],
+            Self::String => &[],
+            Self::Url => &["net/url"],
+        }
+    }
+}
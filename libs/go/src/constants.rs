@@ -0,0 +1,2 @@
+pub const DEFAULT_PACKAGE_NAME: &str = "config";
+pub const DEFAULT_STRUCT_NAME: &str = "ApplicationConfig";
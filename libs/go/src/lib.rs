@@ -0,0 +1,238 @@
+mod atoms;
+mod constants;
+
+use crate::{
+    atoms::AthalarGoKind,
+    constants::{DEFAULT_PACKAGE_NAME, DEFAULT_STRUCT_NAME},
+};
+use anyhow::anyhow;
+use athalar_core::{
+    render_template, AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde::{Deserialize, Serialize};
+use tera::Context as TeraContext;
+
+const GO_TEMPLATE: &str = include_str!("go.tera");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PropertyContext {
+    name: String,
+    field: String,
+    kind: AthalarGoKind,
+    go_type: String,
+    optional: bool,
+    default: Option<String>,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Context {
+    package_name: String,
+    struct_name: String,
+    imports: Vec<String>,
+    properties: Vec<PropertyContext>,
+}
+
+/// Changes the name of an atom to an exported field name, eg: `MAIL_PORT` to `MailPort`.
+fn get_field_name(name: &str) -> String {
+    let field = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let part = part.to_ascii_lowercase();
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+    // identifiers must be exported and can not start with a digit
+    match field.starts_with(|c: char| c.is_ascii_uppercase()) {
+        true => field,
+        false => format!("X{}", field),
+    }
+}
+
+pub fn get_go_contents(binding: &AthalarBinding, atoms: &[AthalarAtom]) -> anyhow::Result<String> {
+    let mut context = match &binding.profile {
+        AthalarAdapter::Go(x) => Context {
+            package_name: x
+                .package_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PACKAGE_NAME.to_string()),
+            struct_name: x
+                .struct_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_STRUCT_NAME.to_string()),
+            imports: vec![],
+            properties: vec![],
+        },
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let mut imports = vec!["fmt", "os", "strings"];
+    for atom in atoms {
+        let field = get_field_name(&atom.name);
+        if let Some(other) = context.properties.iter().find(|p| p.field == field) {
+            return Err(anyhow!(
+                "Variables {:?} and {:?} would both become the field {:?}",
+                other.name,
+                atom.name,
+                field
+            ));
+        }
+        // validators that need a constrained type take precedence over the plain kind
+        let kind = atom
+            .validators
+            .iter()
+            .find_map(AthalarGoKind::from_validator)
+            .unwrap_or_else(|| AthalarGoKind::from(atom.kind));
+        imports.extend(kind.imports());
+        context.properties.push(PropertyContext {
+            name: atom.name.clone(),
+            field,
+            kind,
+            go_type: kind.to_string(),
+            // a variable with a default is always set, so it does not need to be a pointer
+            optional: atom.optional && atom.default_value.is_none(),
+            // json strings are valid go string literals
+            default: atom
+                .default_value
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()?,
+            comment: atom.description.clone(),
+        })
+    }
+    imports.sort();
+    imports.dedup();
+    context.imports = imports.into_iter().map(String::from).collect();
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = render_template(GO_TEMPLATE, &context)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the go profile.
+pub struct GoRenderer;
+
+impl FileRenderer for GoRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_go_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, GoAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    fn binding() -> AthalarBinding {
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from("config.go"))
+            .profile(AthalarAdapter::Go(
+                GoAdapterProfileBuilder::default()
+                    .package_name("settings")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn field_names_are_exported() {
+        assert_eq!(get_field_name("MAIL_PORT"), "MailPort");
+        assert_eq!(get_field_name("api-key"), "ApiKey");
+        assert_eq!(get_field_name("3SCALE_URL"), "X3scaleUrl");
+    }
+
+    #[test]
+    fn colliding_field_names_are_rejected() {
+        let atoms = ["MAIL-PORT", "MAIL_PORT"]
+            .into_iter()
+            .map(|name| {
+                AthalarAtomBuilder::default()
+                    .name(name)
+                    .validators(vec![AtomValidator::Port])
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(get_go_contents(&binding(), &atoms).is_err());
+    }
+
+    #[test]
+    fn fields_are_tagged_and_parsed() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_go_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains("package settings\n"));
+        assert!(contents.contains(
+            "import (\n\t\"fmt\"\n\t\"net/url\"\n\t\"os\"\n\t\"strconv\"\n\t\"strings\"\n)"
+        ));
+        assert!(contents.contains("\tMailPort uint16 `env:\"MAIL_PORT\"`\n"));
+        assert!(contents.contains("\tApiUrl *url.URL `env:\"API_URL\"`\n"));
+        assert!(contents.contains("strconv.ParseUint(value, 10, 16)"));
+    }
+
+    #[test]
+    fn numbers_are_parsed_as_floats() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("RATIO")
+            .validators(vec![AtomValidator::Number])
+            .build()
+            .unwrap()];
+        let contents = get_go_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains("\tRatio float64 `env:\"RATIO\"`\n"));
+        assert!(contents.contains("strconv.ParseFloat(value, 64)"));
+        assert!(contents.contains("\t\"math\"\n"));
+    }
+
+    #[test]
+    fn every_line_of_a_description_is_a_comment() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .description(Some("The mail port\nused for alerts\n".to_string()))
+            .build()
+            .unwrap()];
+        let contents = get_go_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains(
+            "\t// The mail port\n\t// used for alerts\n\tMailPort uint16 `env:\"MAIL_PORT\"`\n"
+        ));
+    }
+
+    #[test]
+    fn defaults_are_parsed_when_the_variable_is_not_set() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .default_value("25")
+            .optional(true)
+            .build()
+            .unwrap()];
+        let contents = get_go_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains("\tMailPort uint16 `env:\"MAIL_PORT\"`\n"));
+        assert!(contents.contains("loadErr.lookup(\"MAIL_PORT\", false, \"25\"); ok {"));
+    }
+}
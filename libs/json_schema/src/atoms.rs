@@ -0,0 +1,43 @@
+use athalar_core::{AtomKind, AtomValidator};
+use serde_json::{json, Map, Value};
+
+/// Returns the JSON schema type of a kind, `Any` does not restrict the type.
+pub fn get_kind_type(kind: AtomKind) -> Option<&'static str> {
+    match kind {
+        AtomKind::Number => Some("number"),
+        AtomKind::String => Some("string"),
+        AtomKind::Any => None,
+    }
+}
+
+/// Adds the keywords that enforce a validator to the schema of a property.
+pub fn apply_validator(av: &AtomValidator, schema: &mut Map<String, Value>) {
+    match av {
+        AtomValidator::Number => {
+            schema.insert("type".to_string(), json!("number"));
+        }
+        AtomValidator::String => {
+            schema.insert("type".to_string(), json!("string"));
+        }
+        AtomValidator::Url => {
+            schema.insert("format".to_string(), json!("uri"));
+        }
+        AtomValidator::Port => {
+            schema.insert("type".to_string(), json!("integer"));
+            schema.insert("minimum".to_string(), json!(0));
+            schema.insert("maximum".to_string(), json!(65535));
+        }
+        AtomValidator::Noop => {}
+    }
+}
+
+/// Converts a default, which is stored the way it appears in the environment, to a value
+/// of the type of the property.
+pub fn get_default(value: &str, kind: AtomKind) -> Value {
+    match kind {
+        AtomKind::Number => serde_json::from_str::<serde_json::Number>(value)
+            .map(Value::Number)
+            .unwrap_or_else(|_| json!(value)),
+        AtomKind::String | AtomKind::Any => json!(value),
+    }
+}
@@ -0,0 +1,2 @@
+pub const DEFAULT_TITLE: &str = "ApplicationConfig";
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
@@ -0,0 +1,118 @@
+mod atoms;
+mod constants;
+
+use crate::{
+    atoms::{apply_validator, get_default, get_kind_type},
+    constants::{DEFAULT_TITLE, SCHEMA_DIALECT},
+};
+use athalar_core::{
+    AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde_json::{json, Map, Value};
+
+pub fn get_json_schema_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let title = match &binding.profile {
+        AthalarAdapter::JsonSchema(x) => {
+            x.title.clone().unwrap_or_else(|| DEFAULT_TITLE.to_string())
+        }
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let mut properties = Map::new();
+    let mut required = vec![];
+    for atom in atoms {
+        let mut schema = Map::new();
+        if let Some(kind_type) = get_kind_type(atom.kind) {
+            schema.insert("type".to_string(), json!(kind_type));
+        }
+        atom.validators
+            .iter()
+            .for_each(|v| apply_validator(v, &mut schema));
+        if let Some(description) = &atom.description {
+            schema.insert("description".to_string(), json!(description));
+        }
+        if let Some(default_value) = &atom.default_value {
+            schema.insert("default".to_string(), get_default(default_value, atom.kind));
+        }
+        if atom.is_required() {
+            required.push(atom.name.clone());
+        }
+        properties.insert(atom.name.clone(), Value::Object(schema));
+    }
+    let document = json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": title,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+    let mut rendered = serde_json::to_string_pretty(&document)?;
+    rendered.push('\n');
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the JSON schema profile.
+pub struct JsonSchemaRenderer;
+
+impl FileRenderer for JsonSchemaRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_json_schema_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, JsonSchemaAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn atoms_are_converted_to_properties() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("backend.schema.json"))
+            .profile(AthalarAdapter::JsonSchema(
+                JsonSchemaAdapterProfileBuilder::default()
+                    .title("Backend")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .description(Some("The api".to_string()))
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_json_schema_contents(&binding, &atoms).unwrap();
+        let document = serde_json::from_str::<Value>(&contents).unwrap();
+        assert_eq!(document["title"], json!("Backend"));
+        assert_eq!(
+            document["properties"]["MAIL_PORT"],
+            json!({"type": "integer", "minimum": 0, "maximum": 65535, "default": 25})
+        );
+        assert_eq!(
+            document["properties"]["API_URL"],
+            json!({"type": "string", "format": "uri", "description": "The api"})
+        );
+        assert_eq!(document["required"], json!(["API_URL"]));
+    }
+}
@@ -0,0 +1,6 @@
+pub const DEFAULT_NAME: &str = "application-config";
+pub const SECRET_NAME_SUFFIX: &str = "-secret";
+pub const LEADING_COMMENT: &str = "# -----------------------------------------------------
+# THIS FILE WAS AUTOMATICALLY GENERATED (DO NOT MODIFY)
+# -----------------------------------------------------
+";
@@ -0,0 +1,184 @@
+mod constants;
+mod manifests;
+
+use crate::{
+    constants::{DEFAULT_NAME, LEADING_COMMENT, SECRET_NAME_SUFFIX},
+    manifests::{Manifest, Metadata},
+};
+use athalar_core::{
+    AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde_yaml::{Mapping, Value};
+
+pub fn get_kubernetes_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let profile = match &binding.profile {
+        AthalarAdapter::Kubernetes(x) => x,
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let name = profile
+        .name
+        .clone()
+        .unwrap_or_else(|| DEFAULT_NAME.to_string());
+    let secret_name = profile
+        .secret_name
+        .clone()
+        .unwrap_or_else(|| format!("{}{}", name, SECRET_NAME_SUFFIX));
+    let mut data = Mapping::new();
+    let mut string_data = Mapping::new();
+    for atom in atoms {
+        if atom.secret {
+            // only the keys are generated, the values must be filled in during deployment
+            string_data.insert(Value::from(atom.name.clone()), Value::from(""));
+        } else if let Some(default_value) = &atom.default_value {
+            data.insert(
+                Value::from(atom.name.clone()),
+                Value::from(default_value.clone()),
+            );
+        } else if !atom.optional {
+            // like the secrets, required values have to be filled in during deployment (an
+            // optional one is left out, since an empty value is not the same as an unset one)
+            data.insert(Value::from(atom.name.clone()), Value::from(""));
+        }
+    }
+    let mut documents = vec![];
+    let mut env_from = vec![];
+    if !data.is_empty() {
+        let metadata = Metadata {
+            name: name.clone(),
+            namespace: profile.namespace.clone(),
+        };
+        documents.push(serde_yaml::to_string(&Manifest::config_map(
+            metadata, data,
+        ))?);
+        env_from.push(format!("#   - configMapRef:\n#       name: {}\n", name));
+    }
+    if !string_data.is_empty() {
+        let metadata = Metadata {
+            name: secret_name.clone(),
+            namespace: profile.namespace.clone(),
+        };
+        documents.push(serde_yaml::to_string(&Manifest::secret(
+            metadata,
+            string_data,
+        ))?);
+        env_from.push(format!("#   - secretRef:\n#       name: {}\n", secret_name));
+    }
+    let mut rendered = LEADING_COMMENT.to_string();
+    rendered.push_str(&documents.join("---\n"));
+    if profile.env_from.unwrap_or(false) && !env_from.is_empty() {
+        rendered.push_str("\n# Add the following to the container spec of a Deployment:\n#\n");
+        rendered.push_str("# envFrom:\n");
+        rendered.push_str(&env_from.join(""));
+    }
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the kubernetes profile.
+pub struct KubernetesRenderer;
+
+impl FileRenderer for KubernetesRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_kubernetes_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, KubernetesAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn secrets_are_separated_from_defaults() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("config.yaml"))
+            .profile(AthalarAdapter::Kubernetes(
+                KubernetesAdapterProfileBuilder::default()
+                    .name("backend")
+                    .namespace("apps")
+                    .env_from(true)
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("MAIL_HOST")
+                .validators(vec![AtomValidator::String])
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_KEY")
+                .validators(vec![AtomValidator::String])
+                .secret(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_kubernetes_contents(&binding, &atoms).unwrap();
+        let expected = "apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: backend
+  namespace: apps
+data:
+  MAIL_PORT: '25'
+  MAIL_HOST: ''
+---
+apiVersion: v1
+kind: Secret
+metadata:
+  name: backend-secret
+  namespace: apps
+type: Opaque
+stringData:
+  API_KEY: ''
+";
+        assert!(contents.starts_with(LEADING_COMMENT));
+        assert!(contents.contains(expected));
+        assert!(contents.ends_with(
+            "# envFrom:\n#   - configMapRef:\n#       name: backend\n#   - secretRef:\n#       name: backend-secret\n"
+        ));
+    }
+
+    #[test]
+    fn required_values_without_a_default_are_left_empty() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("config.yaml"))
+            .profile(AthalarAdapter::Kubernetes(
+                KubernetesAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = ["MAIL_HOST", "MAIL_USER"]
+            .into_iter()
+            .map(|name| {
+                AthalarAtomBuilder::default()
+                    .name(name)
+                    .validators(vec![AtomValidator::String])
+                    .optional(name == "MAIL_USER")
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let contents = get_kubernetes_contents(&binding, &atoms).unwrap();
+        assert!(contents.ends_with("data:\n  MAIL_HOST: ''\n"));
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+use serde_yaml::Mapping;
+
+#[derive(Debug, Serialize)]
+pub struct Metadata {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+/// The subset of a kubernetes `ConfigMap` or `Secret` that athalar generates.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub api_version: &'static str,
+
+    pub kind: &'static str,
+
+    pub metadata: Metadata,
+
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub secret_type: Option<&'static str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Mapping>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub string_data: Option<Mapping>,
+}
+
+impl Manifest {
+    pub fn config_map(metadata: Metadata, data: Mapping) -> Self {
+        Self {
+            api_version: "v1",
+            kind: "ConfigMap",
+            metadata,
+            secret_type: None,
+            data: Some(data),
+            string_data: None,
+        }
+    }
+
+    pub fn secret(metadata: Metadata, string_data: Mapping) -> Self {
+        Self {
+            api_version: "v1",
+            kind: "Secret",
+            metadata,
+            secret_type: Some("Opaque"),
+            data: None,
+            string_data: Some(string_data),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub const DEFAULT_TITLE: &str = "Configuration";
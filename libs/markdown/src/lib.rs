@@ -0,0 +1,148 @@
+mod constants;
+
+use crate::constants::DEFAULT_TITLE;
+use athalar_core::{
+    AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde::{Deserialize, Serialize};
+use tera::{Context as TeraContext, Tera};
+
+const MARKDOWN_TEMPLATE: &str = include_str!("markdown.tera");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PropertyContext {
+    name: String,
+    kind: String,
+    validators: String,
+    default: String,
+    required: String,
+    secret: String,
+    description: String,
+    partial: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Context {
+    title: String,
+    properties: Vec<PropertyContext>,
+}
+
+/// Escapes text so that it can be placed inside a table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn yes_or_no(value: bool) -> String {
+    match value {
+        true => "Yes".to_string(),
+        false => "No".to_string(),
+    }
+}
+
+pub fn get_markdown_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let mut context = match &binding.profile {
+        AthalarAdapter::Markdown(x) => Context {
+            title: x.title.clone().unwrap_or_else(|| DEFAULT_TITLE.to_string()),
+            properties: vec![],
+        },
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    for atom in atoms {
+        let validators = atom
+            .validators
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        // the values of secrets are never written in plain text
+        let default = match (&atom.default_value, atom.secret) {
+            (Some(_), true) => "_hidden_".to_string(),
+            (Some(default_value), false) => format!("`{}`", escape_cell(default_value)),
+            (None, _) => "-".to_string(),
+        };
+        context.properties.push(PropertyContext {
+            name: atom.name.clone(),
+            kind: atom.kind.to_string(),
+            validators: match validators.is_empty() {
+                true => "-".to_string(),
+                false => validators.join(", "),
+            },
+            default,
+            required: yes_or_no(atom.is_required()),
+            secret: yes_or_no(atom.secret),
+            description: atom
+                .description
+                .as_deref()
+                .map(escape_cell)
+                .unwrap_or_else(|| "-".to_string()),
+            partial: atom.partial.clone().unwrap_or_else(|| "-".to_string()),
+        })
+    }
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = Tera::one_off(MARKDOWN_TEMPLATE, &context, false)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the markdown profile.
+pub struct MarkdownRenderer;
+
+impl FileRenderer for MarkdownRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_markdown_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, MarkdownAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn atoms_are_listed_in_a_table() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("CONFIGURATION.md"))
+            .profile(AthalarAdapter::Markdown(
+                MarkdownAdapterProfileBuilder::default()
+                    .title("Backend")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .description(Some("The port | number".to_string()))
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_KEY")
+                .validators(vec![AtomValidator::String])
+                .default_value("insecure")
+                .secret(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_markdown_contents(&binding, &atoms).unwrap();
+        assert!(contents.contains("\n# Backend\n"));
+        assert!(contents.contains(
+            "| `MAIL_PORT` | Number | Port | `25` | No | No | The port \\| number | - |\n"
+        ));
+        assert!(
+            contents.contains("| `API_KEY` | String | String | _hidden_ | No | Yes | - | - |\n")
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Runs an external executable to generate the files of a binding. When generating, the
+//! plugin is run once and its files are only written if it reports no severe problems.
+//!
+//! The plugin receives a JSON object on its standard input with the following keys:
+//!
+//! - `generator.name`: the name of the generator that the binding belongs to.
+//! - `binding.output`: the fully qualified output path of the binding.
+//! - `options`: the `options` mapping of the binding profile, as written in the generator.
+//! - `atoms`: the atoms of the generator, in the order they were included. Each of them
+//!   is an [AtomContext], with the same keys as in the context of the template adapter.
+//!
+//! It must exit successfully and print a JSON object on its standard output, with the
+//! following keys (both of them can be left out):
+//!
+//! - `files`: the files that should be written. Each of them has the `contents` of the file
+//!   and an optional `path` relative to `athalar.toml`. The output of the binding is used
+//!   when the `path` is left out.
+//! - `diagnostics`: problems that the plugin found, which are added to the validation
+//!   report. Each of them has a `level` (`Severe`, `Warning` or `Info`) and a `message`.
+//!
+//! Anything that the plugin writes to its standard error is passed through to the user.
+
+use athalar_core::{
+    reporting::{data::GeneratorReportCreator, ReportLevel, ReportMessage},
+    AthalarAdapter, AthalarAtom, AthalarBinding, AtomContext, FinalFile, PluginAdapterProfile,
+    RenderContext, Renderer, UnsupportedProfileError,
+};
+use relative_path::RelativePath;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Mapping;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+#[derive(Debug, Serialize)]
+struct GeneratorContext {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct BindingContext {
+    output: PathBuf,
+}
+
+#[derive(Debug, Serialize)]
+struct PluginRequest {
+    generator: GeneratorContext,
+    binding: BindingContext,
+    options: Mapping,
+    atoms: Vec<AtomContext>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginFile {
+    path: Option<PathBuf>,
+    contents: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginDiagnostic {
+    level: ReportLevel,
+    message: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PluginResponse {
+    #[serde(default)]
+    files: Vec<PluginFile>,
+    #[serde(default)]
+    diagnostics: Vec<PluginDiagnostic>,
+}
+
+fn get_profile(binding: &AthalarBinding) -> anyhow::Result<&PluginAdapterProfile> {
+    match &binding.profile {
+        AthalarAdapter::Plugin(x) => Ok(x),
+        other => Err(UnsupportedProfileError(other.into()).into()),
+    }
+}
+
+fn get_request(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+    context: &RenderContext,
+) -> anyhow::Result<PluginRequest> {
+    Ok(PluginRequest {
+        generator: GeneratorContext {
+            name: context.generator_name.to_string(),
+        },
+        binding: BindingContext {
+            output: binding.output(context.project_source),
+        },
+        options: get_profile(binding)?.options.clone(),
+        atoms: atoms.iter().map(AtomContext::from).collect(),
+    })
+}
+
+/// Plain names are looked up in `PATH` and absolute paths are used as they are, everything
+/// else is relative to the project.
+fn get_program(command: &str, project_source: &Path) -> PathBuf {
+    if Path::new(command).is_absolute() || !command.contains('/') {
+        return PathBuf::from(command);
+    }
+    RelativePath::new(command).to_logical_path(project_source)
+}
+
+fn run_plugin(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+    context: &RenderContext,
+) -> anyhow::Result<PluginResponse> {
+    let profile = get_profile(binding)?;
+    let request = serde_json::to_vec(&get_request(binding, atoms, context)?)?;
+    let mut child = Command::new(get_program(&profile.command, context.project_source))
+        .args(&profile.args)
+        .current_dir(context.project_source)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Unable to run plugin {:?}: {}", profile.command, e))?;
+    let mut stdin = child.stdin.take().unwrap();
+    // write from a separate thread so that a plugin writing a lot of output before reading
+    // all of its input can not block us
+    let writer = thread::spawn(move || stdin.write_all(&request));
+    let output = child.wait_with_output()?;
+    // a plugin is allowed to exit without reading its input
+    writer.join().unwrap().ok();
+    if !output.status.success() {
+        anyhow::bail!("Plugin {:?} failed with {}", profile.command, output.status);
+    }
+    serde_json::from_slice(&output.stdout).map_err(|e| {
+        anyhow::anyhow!(
+            "Plugin {:?} returned an invalid response: {}",
+            profile.command,
+            e
+        )
+    })
+}
+
+// the files that a plugin returned, with their paths resolved against the project
+fn get_final_files(
+    files: Vec<PluginFile>,
+    binding: &AthalarBinding,
+    context: &RenderContext,
+) -> anyhow::Result<Vec<FinalFile>> {
+    let mut final_files = vec![];
+    for file in files {
+        let path = match file.path {
+            Some(path) => RelativePath::from_path(&path)?.to_logical_path(context.project_source),
+            None => binding.output(context.project_source),
+        };
+        final_files.push(FinalFile {
+            path,
+            contents: file.contents,
+        });
+    }
+    Ok(final_files)
+}
+
+fn get_reports(command: &str, diagnostics: Vec<PluginDiagnostic>) -> Vec<ReportMessage> {
+    diagnostics
+        .into_iter()
+        .map(|d| GeneratorReportCreator::plugin_diagnostic(command, d.level, &d.message))
+        .collect()
+}
+
+/// Renders the bindings that use the plugin profile.
+pub struct PluginRenderer;
+
+impl Renderer for PluginRenderer {
+    fn render(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<Vec<FinalFile>> {
+        let response = run_plugin(binding, atoms, context)?;
+        let severe = response
+            .diagnostics
+            .iter()
+            .filter(|d| d.level == ReportLevel::Severe)
+            .map(|d| d.message.as_str())
+            .collect::<Vec<_>>();
+        if !severe.is_empty() {
+            anyhow::bail!(
+                "Plugin {:?} reported errors: {}",
+                get_profile(binding)?.command,
+                severe.join(", ")
+            );
+        }
+        get_final_files(response.files, binding, context)
+    }
+
+    fn validate(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<Vec<ReportMessage>> {
+        let command = &get_profile(binding)?.command;
+        let response = run_plugin(binding, atoms, context)?;
+        Ok(get_reports(command, response.diagnostics))
+    }
+
+    // the files and the diagnostics come from the same run of the plugin
+    fn render_with_reports(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<(Vec<FinalFile>, Vec<ReportMessage>)> {
+        let command = &get_profile(binding)?.command;
+        let response = run_plugin(binding, atoms, context)?;
+        let reports = get_reports(command, response.diagnostics);
+        if reports.iter().any(|r| r.level == ReportLevel::Severe) {
+            return Ok((vec![], reports));
+        }
+        Ok((get_final_files(response.files, binding, context)?, reports))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, PluginAdapterProfileBuilder,
+    };
+    use serde_yaml::Value;
+    use std::{env, fs};
+
+    fn binding(script: &str) -> AthalarBinding {
+        let mut options = Mapping::new();
+        options.insert(Value::from("module"), Value::from("network"));
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from("variables.tf"))
+            .profile(AthalarAdapter::Plugin(
+                PluginAdapterProfileBuilder::default()
+                    .command("sh")
+                    .args(vec!["-c".to_string(), script.to_string()])
+                    .options(options)
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    fn context() -> RenderContext<'static> {
+        RenderContext {
+            generator_name: "backend",
+            project_source: Path::new("/tmp"),
+        }
+    }
+
+    fn atoms() -> Vec<AthalarAtom> {
+        vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .build()
+            .unwrap()]
+    }
+
+    #[test]
+    fn request_contains_resolved_generator() {
+        let request = get_request(&binding("true"), &atoms(), &context()).unwrap();
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "generator": { "name": "backend" },
+                "binding": { "output": "/tmp/variables.tf" },
+                "options": { "module": "network" },
+                "atoms": [{
+                    "name": "MAIL_PORT",
+                    "kind": "Number",
+                    "validators": ["Port"],
+                    "optional": false,
+                    "required": true,
+                    "secret": false,
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn only_relative_commands_are_resolved_in_the_project() {
+        let project_source = Path::new("/project");
+        assert_eq!(get_program("sh", project_source), PathBuf::from("sh"));
+        assert_eq!(
+            get_program("/usr/bin/plugin", project_source),
+            PathBuf::from("/usr/bin/plugin")
+        );
+        assert_eq!(
+            get_program("./scripts/plugin", project_source),
+            PathBuf::from("/project/scripts/plugin")
+        );
+    }
+
+    #[test]
+    fn files_default_to_binding_output() {
+        let binding = binding(
+            r#"cat > /dev/null; echo '{"files": [{"contents": "a"}, {"path": "b.tf", "contents": "b"}]}'"#,
+        );
+        let files = PluginRenderer
+            .render(&binding, &atoms(), &context())
+            .unwrap();
+        assert_eq!(files[0].path, PathBuf::from("/tmp/variables.tf"));
+        assert_eq!(files[0].contents, "a");
+        assert_eq!(files[1].path, PathBuf::from("/tmp/b.tf"));
+    }
+
+    #[test]
+    fn diagnostics_are_reported() {
+        let binding = binding(
+            r#"echo '{"diagnostics": [{"level": "Warning", "message": "MAIL_PORT is unused"}]}'"#,
+        );
+        let reports = PluginRenderer
+            .validate(&binding, &atoms(), &context())
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].level, ReportLevel::Warning);
+        assert_eq!(
+            reports[0].message,
+            "Plugin \"sh\" reported: MAIL_PORT is unused"
+        );
+    }
+
+    #[test]
+    fn plugin_is_run_once_when_rendering_with_reports() {
+        let runs = env::temp_dir().join("athalar_plugin_runs_test");
+        fs::remove_file(&runs).ok();
+        let binding = binding(&format!(
+            r#"echo run >> {:?}; echo '{{"files": [{{"contents": "a"}}], "diagnostics": [{{"level": "Warning", "message": "unused"}}]}}'"#,
+            runs
+        ));
+        let (files, reports) = PluginRenderer
+            .render_with_reports(&binding, &atoms(), &context())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(fs::read_to_string(&runs).unwrap(), "run\n");
+    }
+
+    #[test]
+    fn severe_diagnostics_leave_out_the_files() {
+        let binding = binding(
+            r#"echo '{"files": [{"contents": "a"}], "diagnostics": [{"level": "Severe", "message": "no"}]}'"#,
+        );
+        let (files, reports) = PluginRenderer
+            .render_with_reports(&binding, &atoms(), &context())
+            .unwrap();
+        assert!(files.is_empty());
+        assert_eq!(reports[0].level, ReportLevel::Severe);
+    }
+
+    #[test]
+    fn failing_plugin_is_an_error() {
+        let result = PluginRenderer.render(&binding("exit 3"), &atoms(), &context());
+        assert!(result.is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use athalar_core::AtomKind;
+use athalar_core::{AtomKind, AtomValidator};
 use strum_macros::Display;
 
 #[derive(Debug, Display)]
@@ -22,3 +22,33 @@ impl From<AtomKind> for AthalarPythonKind {
         }
     }
 }
+
+/// The pydantic types that enforce validators which can not be expressed by the kind of
+/// the atom alone.
+#[derive(Debug, Display)]
+pub enum AthalarPythonConstrainedKind {
+    #[strum(serialize = "AnyUrl")]
+    Url,
+
+    #[strum(serialize = "conint(ge=0, le=65535)")]
+    Port,
+}
+
+impl AthalarPythonConstrainedKind {
+    /// Returns the constrained type for a validator, if the validator needs one.
+    pub fn from_validator(av: &AtomValidator) -> Option<Self> {
+        match av {
+            AtomValidator::Url => Some(Self::Url),
+            AtomValidator::Port => Some(Self::Port),
+            AtomValidator::Number | AtomValidator::String | AtomValidator::Noop => None,
+        }
+    }
+
+    /// The name that needs to be imported from `pydantic` to use this type.
+    pub fn import(&self) -> &'static str {
+        match self {
+            Self::Url => "AnyUrl",
+            Self::Port => "conint",
+        }
+    }
+}
@@ -1,10 +1,17 @@
 mod atoms;
 mod constants;
 
-use crate::{atoms::AthalarPythonKind, constants::DEFAULT_CLASS_NAME};
-use athalar_core::{AthalarAdapter, AthalarAtom, AthalarBinding};
+use crate::{
+    atoms::{AthalarPythonConstrainedKind, AthalarPythonKind},
+    constants::DEFAULT_CLASS_NAME,
+};
+use athalar_core::{
+    render_template, AthalarAdapter, AthalarAtom, AthalarBinding, AtomKind, FileRenderer,
+    RenderContext, UnsupportedProfileError,
+};
 use serde::{Deserialize, Serialize};
-use tera::{Context as TeraContext, Tera};
+use std::{fs, path::Path};
+use tera::Context as TeraContext;
 
 const PYTHON_TEMPLATE: &str = include_str!("python.tera");
 
@@ -12,38 +19,321 @@ const PYTHON_TEMPLATE: &str = include_str!("python.tera");
 struct PropertyContext {
     name: String,
     kind: String,
+    default: Option<String>,
     comment: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Context {
     class_name: String,
+    base_class: String,
+    pydantic_imports: Vec<String>,
+    pydantic_settings_imports: Vec<String>,
+    typing_imports: Vec<String>,
+    settings_options: Vec<String>,
     properties: Vec<PropertyContext>,
 }
 
+/// Writes a value as a python string literal. Control characters are written with the
+/// `\xNN` escape, since python does not understand the `\u{..}` escapes of rust.
+fn get_string_literal(value: &str) -> String {
+    let mut literal = String::from('"');
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_ascii_control() => literal.push_str(&format!("\\x{:02x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Writes the default of an atom as a python literal. Numbers are written as they are,
+/// everything else (including numbers that python would not understand) as a string.
+fn get_default_literal(atom: &AthalarAtom, value: &str) -> String {
+    match atom.kind {
+        AtomKind::Number if value.parse::<f64>().is_ok_and(|n| n.is_finite()) => value.to_string(),
+        _ => get_string_literal(value),
+    }
+}
+
 pub fn get_python_contents(
     binding: &AthalarBinding,
-    atoms: &Vec<AthalarAtom>,
+    atoms: &[AthalarAtom],
+    project_source: &Path,
 ) -> anyhow::Result<String> {
     let mut context = match &binding.profile {
-        AthalarAdapter::ClassValidator(_) => unimplemented!(),
         AthalarAdapter::Pydantic(x) => Context {
             class_name: x
                 .class_name
                 .clone()
                 .unwrap_or_else(|| DEFAULT_CLASS_NAME.to_string()),
+            base_class: "BaseModel".to_string(),
+            pydantic_imports: vec!["BaseModel".to_string()],
+            pydantic_settings_imports: vec![],
+            typing_imports: vec![],
+            settings_options: vec![],
             properties: vec![],
         },
+        AthalarAdapter::PydanticSettings(x) => {
+            let mut settings_options = vec![];
+            if let Some(env_prefix) = &x.env_prefix {
+                settings_options.push(format!("env_prefix={}", get_string_literal(env_prefix)));
+            }
+            if let Some(env_file) = &x.env_file {
+                settings_options.push(format!("env_file={}", get_string_literal(env_file)));
+            }
+            let mut pydantic_settings_imports = vec!["BaseSettings".to_string()];
+            if !settings_options.is_empty() {
+                pydantic_settings_imports.push("SettingsConfigDict".to_string());
+            }
+            Context {
+                class_name: x
+                    .class_name
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_CLASS_NAME.to_string()),
+                base_class: "BaseSettings".to_string(),
+                pydantic_imports: vec![],
+                pydantic_settings_imports,
+                typing_imports: vec![],
+                settings_options,
+                properties: vec![],
+            }
+        }
+        other => return Err(UnsupportedProfileError(other.into()).into()),
     };
+    let mut pydantic_imports = context.pydantic_imports.clone();
+    let mut typing_imports = vec![];
     for atom in atoms {
+        // validators that need a constrained type take precedence over the plain kind
+        let kind = match atom
+            .validators
+            .iter()
+            .find_map(AthalarPythonConstrainedKind::from_validator)
+        {
+            Some(constrained) => {
+                pydantic_imports.push(constrained.import().to_string());
+                constrained.to_string()
+            }
+            None => {
+                let kind = AthalarPythonKind::from(atom.kind);
+                if let AthalarPythonKind::Any = kind {
+                    typing_imports.push(kind.to_string());
+                }
+                kind.to_string()
+            }
+        };
+        // a variable with a default is always set, so only the ones without a default can
+        // be `None`
+        let (kind, default) = match &atom.default_value {
+            Some(value) => (kind, Some(get_default_literal(atom, value))),
+            None if atom.optional => {
+                typing_imports.push("Optional".to_string());
+                (format!("Optional[{}]", kind), Some("None".to_string()))
+            }
+            None => (kind, None),
+        };
         context.properties.push(PropertyContext {
             name: atom.name.clone(),
-            kind: AthalarPythonKind::from(atom.kind).to_string(),
+            kind,
+            default,
             comment: atom.description.clone(),
-            // TODO: Handle validators
         })
     }
+    pydantic_imports.sort();
+    pydantic_imports.dedup();
+    typing_imports.sort();
+    typing_imports.dedup();
+    context.pydantic_imports = pydantic_imports;
+    context.typing_imports = typing_imports;
     let context = TeraContext::from_serialize(context)?;
-    let rendered = Tera::one_off(PYTHON_TEMPLATE, &context, false)?;
+    // the project can replace the built-in template, it receives the same context
+    let template = match binding.template(project_source)? {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("Unable to read template {:?}: {}", path, e))?,
+        None => PYTHON_TEMPLATE.to_string(),
+    };
+    let rendered = render_template(&template, &context)?;
     Ok(rendered)
 }
+
+/// Renders the bindings that use the pydantic or pydantic settings profiles.
+pub struct PythonRenderer;
+
+impl FileRenderer for PythonRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_python_contents(binding, atoms, context.project_source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, PydanticAdapterProfileBuilder,
+        PydanticSettingsAdapterProfileBuilder,
+    };
+    use std::{env, path::PathBuf};
+
+    fn binding() -> AthalarBinding {
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from("backend.py"))
+            .profile(AthalarAdapter::Pydantic(
+                PydanticAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn validators_are_translated_to_constrained_types() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_python_contents(&binding(), &atoms, Path::new(".")).unwrap();
+        assert!(contents.starts_with("from pydantic import AnyUrl, BaseModel, conint\n"));
+        assert!(contents.contains("    MAIL_PORT: conint(ge=0, le=65535)\n"));
+        assert!(contents.contains("    API_URL: AnyUrl\n"));
+    }
+
+    #[test]
+    fn any_kind_is_imported_from_typing() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAGIC")
+            .validators(vec![AtomValidator::Noop])
+            .build()
+            .unwrap()];
+        let contents = get_python_contents(&binding(), &atoms, Path::new(".")).unwrap();
+        assert!(contents.starts_with("from typing import Any\n\nfrom pydantic import BaseModel\n"));
+        assert!(contents.contains("    MAGIC: Any\n"));
+    }
+
+    #[test]
+    fn optional_atoms_and_defaults_can_be_left_unset() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("MAIL_HOST")
+                .validators(vec![AtomValidator::String])
+                .default_value("mail \"local\"")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_python_contents(&binding(), &atoms, Path::new(".")).unwrap();
+        assert!(contents.starts_with("from typing import Optional\n"));
+        assert!(contents.contains("    MAIL_PORT: conint(ge=0, le=65535) = 25\n"));
+        assert!(contents.contains("    MAIL_HOST: str = \"mail \\\"local\\\"\"\n"));
+        assert!(contents.contains("    API_URL: Optional[AnyUrl] = None\n"));
+    }
+
+    #[test]
+    fn every_line_of_a_description_is_a_comment() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .description(Some("The mail port\nused for alerts\n".to_string()))
+            .build()
+            .unwrap()];
+        let contents = get_python_contents(&binding(), &atoms, Path::new(".")).unwrap();
+        assert!(contents.contains(
+            "class ApplicationConfig(BaseModel):\n    # The mail port\n    # used for alerts\n    MAIL_PORT: conint(ge=0, le=65535)\n"
+        ));
+    }
+
+    #[test]
+    fn settings_profile_reads_environment() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("backend.py"))
+            .profile(AthalarAdapter::PydanticSettings(
+                PydanticSettingsAdapterProfileBuilder::default()
+                    .class_name("Settings")
+                    .env_prefix("APP_")
+                    .env_file(".env")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_HOST")
+            .validators(vec![AtomValidator::String])
+            .build()
+            .unwrap()];
+        let contents = get_python_contents(&binding, &atoms, Path::new(".")).unwrap();
+        assert!(contents
+            .starts_with("from pydantic_settings import BaseSettings, SettingsConfigDict\n"));
+        assert!(contents.contains(
+            "class Settings(BaseSettings):\n    model_config = SettingsConfigDict(env_prefix=\"APP_\", env_file=\".env\")\n"
+        ));
+    }
+
+    #[test]
+    fn string_literals_use_python_escapes() {
+        assert_eq!(get_string_literal("APP_"), "\"APP_\"");
+        assert_eq!(
+            get_string_literal("a\"b\\c\nd\u{1b}é"),
+            "\"a\\\"b\\\\c\\nd\\x1bé\""
+        );
+    }
+
+    #[test]
+    fn project_template_replaces_built_in_one() {
+        let project_source = env::temp_dir().join("athalar_python_test");
+        fs::create_dir_all(&project_source).unwrap();
+        fs::write(
+            project_source.join("python.tera"),
+            "# generated\nclass {{ class_name }}({{ base_class }}):\n{% for property in properties %}    {{ property.name }}: {{ property.kind }}\n{% endfor %}",
+        )
+        .unwrap();
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("backend.py"))
+            .profile(AthalarAdapter::Pydantic(
+                PydanticAdapterProfileBuilder::default()
+                    .template("python.tera")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .build()
+            .unwrap()];
+        let contents = get_python_contents(&binding, &atoms, &project_source).unwrap();
+        assert_eq!(
+            contents,
+            "# generated\nclass ApplicationConfig(BaseModel):\n    MAIL_PORT: conint(ge=0, le=65535)\n"
+        );
+    }
+}
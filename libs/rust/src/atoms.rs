@@ -0,0 +1,47 @@
+use athalar_core::{AtomKind, AtomValidator};
+use strum_macros::Display;
+
+#[derive(Debug, Display)]
+pub enum AthalarRustKind {
+    #[strum(serialize = "f64")]
+    Number,
+
+    #[strum(serialize = "String")]
+    String,
+
+    // environment variables are always strings, so they can be kept as is
+    #[strum(serialize = "String")]
+    Any,
+}
+
+impl From<AtomKind> for AthalarRustKind {
+    fn from(av: AtomKind) -> Self {
+        match av {
+            AtomKind::Number => Self::Number,
+            AtomKind::String => Self::String,
+            AtomKind::Any => Self::Any,
+        }
+    }
+}
+
+/// The rust types that enforce validators which can not be expressed by the kind of the
+/// atom alone.
+#[derive(Debug, Display)]
+pub enum AthalarRustConstrainedKind {
+    #[strum(serialize = "url::Url")]
+    Url,
+
+    #[strum(serialize = "u16")]
+    Port,
+}
+
+impl AthalarRustConstrainedKind {
+    /// Returns the constrained type for a validator, if the validator needs one.
+    pub fn from_validator(av: &AtomValidator) -> Option<Self> {
+        match av {
+            AtomValidator::Url => Some(Self::Url),
+            AtomValidator::Port => Some(Self::Port),
+            AtomValidator::Number | AtomValidator::String | AtomValidator::Noop => None,
+        }
+    }
+}
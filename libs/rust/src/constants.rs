@@ -0,0 +1 @@
+pub const DEFAULT_STRUCT_NAME: &str = "ApplicationConfig";
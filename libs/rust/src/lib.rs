@@ -0,0 +1,224 @@
+mod atoms;
+mod constants;
+
+use crate::{
+    atoms::{AthalarRustConstrainedKind, AthalarRustKind},
+    constants::DEFAULT_STRUCT_NAME,
+};
+use anyhow::anyhow;
+use athalar_core::{
+    render_template, AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde::{Deserialize, Serialize};
+use tera::Context as TeraContext;
+
+const RUST_TEMPLATE: &str = include_str!("rust.tera");
+
+/// The strict and reserved keywords of rust, which can not be used as field names
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PropertyContext {
+    name: String,
+    field: String,
+    kind: String,
+    optional: bool,
+    default: Option<String>,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Context {
+    struct_name: String,
+    properties: Vec<PropertyContext>,
+}
+
+/// Changes the name of an atom to a snake case field name, eg: `MAIL-PORT` to `mail_port`.
+/// Keywords get a trailing underscore, eg: `TYPE` to `type_`, since some of them (like
+/// `self`) can not be used as raw identifiers.
+fn get_field_name(name: &str) -> String {
+    let field = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '_',
+        })
+        .collect::<String>();
+    if field.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", field)
+    } else if KEYWORDS.contains(&field.as_str()) {
+        format!("{}_", field)
+    } else {
+        field
+    }
+}
+
+pub fn get_rust_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+) -> anyhow::Result<String> {
+    let mut context = match &binding.profile {
+        AthalarAdapter::Rust(x) => Context {
+            struct_name: x
+                .struct_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_STRUCT_NAME.to_string()),
+            properties: vec![],
+        },
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    for atom in atoms {
+        let field = get_field_name(&atom.name);
+        if let Some(other) = context.properties.iter().find(|p| p.field == field) {
+            return Err(anyhow!(
+                "Variables {:?} and {:?} would both become the field {:?}",
+                other.name,
+                atom.name,
+                field
+            ));
+        }
+        // validators that need a constrained type take precedence over the plain kind
+        let kind = match atom
+            .validators
+            .iter()
+            .find_map(AthalarRustConstrainedKind::from_validator)
+        {
+            Some(constrained) => constrained.to_string(),
+            None => AthalarRustKind::from(atom.kind).to_string(),
+        };
+        context.properties.push(PropertyContext {
+            name: atom.name.clone(),
+            field,
+            kind,
+            // a variable with a default is always set, so it does not need to be an `Option`
+            optional: atom.optional && atom.default_value.is_none(),
+            default: atom
+                .default_value
+                .as_ref()
+                .map(|value| format!("{:?}", value)),
+            comment: atom.description.clone(),
+        })
+    }
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = render_template(RUST_TEMPLATE, &context)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the rust profile.
+pub struct RustRenderer;
+
+impl FileRenderer for RustRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_rust_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, RustAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    fn binding() -> AthalarBinding {
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from("config.rs"))
+            .profile(AthalarAdapter::Rust(
+                RustAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn field_names_are_snake_case() {
+        assert_eq!(get_field_name("MAIL_PORT"), "mail_port");
+        assert_eq!(get_field_name("api-key"), "api_key");
+        assert_eq!(get_field_name("3SCALE_URL"), "_3scale_url");
+        assert_eq!(get_field_name("TYPE"), "type_");
+        assert_eq!(get_field_name("SELF"), "self_");
+    }
+
+    #[test]
+    fn colliding_field_names_are_rejected() {
+        let atoms = ["MAIL-PORT", "MAIL_PORT"]
+            .into_iter()
+            .map(|name| {
+                AthalarAtomBuilder::default()
+                    .name(name)
+                    .validators(vec![AtomValidator::Port])
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(get_rust_contents(&binding(), &atoms).is_err());
+    }
+
+    #[test]
+    fn validators_and_optional_atoms_decide_the_type() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .description(Some("The mail port".to_string()))
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_rust_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains(
+            "    /// The mail port\n    #[serde(rename = \"MAIL_PORT\")]\n    pub mail_port: u16,\n"
+        ));
+        assert!(contents.contains("    pub api_url: Option<url::Url>,\n"));
+        assert!(contents.contains("            mail_port: value_0.unwrap(),\n"));
+        assert!(contents.contains("            api_url: value_1,\n"));
+    }
+
+    #[test]
+    fn every_line_of_a_description_is_a_doc_comment() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .description(Some("The mail port\nused for alerts\n".to_string()))
+            .build()
+            .unwrap()];
+        let contents = get_rust_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains(
+            "    /// The mail port\n    /// used for alerts\n    #[serde(rename = \"MAIL_PORT\")]\n"
+        ));
+    }
+
+    #[test]
+    fn defaults_are_parsed_when_the_variable_is_not_set() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_HOST")
+            .validators(vec![AtomValidator::String])
+            .default_value("mail \"local\"")
+            .optional(true)
+            .build()
+            .unwrap()];
+        let contents = get_rust_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains("    pub mail_host: String,\n"));
+        assert!(contents.contains(
+            "parse_variable::<String>(\"MAIL_HOST\", Some(\"mail \\\"local\\\"\"), false, &mut error);"
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+//! Renders a [tera](https://tera.netlify.app/) template supplied by the project. The
+//! template receives the following context, which is kept stable across releases:
+//!
+//! - `generator.name`: the name of the generator that the binding belongs to.
+//! - `atoms`: the atoms of the generator, in the order they were included. Each of them
+//!   has a `name`, `kind` (`Number`, `String` or `Any`), `validators` (a list of names
+//!   like `Port` or `Url`), `default`, `description`, `partial` (the name of the partial
+//!   that declared it) and the `optional`, `required` and `secret` flags. The `default`,
+//!   `description` and `partial` are absent when they are not set.
+//! - `options`: the `options` mapping of the binding profile, as written in the generator.
+
+use athalar_core::{
+    AthalarAdapter, AthalarAtom, AthalarBinding, AtomContext, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use relative_path::RelativePath;
+use serde::Serialize;
+use serde_yaml::Mapping;
+use std::{fs, path::Path};
+use tera::{Context as TeraContext, Tera};
+
+#[derive(Debug, Serialize)]
+struct GeneratorContext {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Context {
+    generator: GeneratorContext,
+    atoms: Vec<AtomContext>,
+    options: Mapping,
+}
+
+pub fn get_template_contents(
+    binding: &AthalarBinding,
+    atoms: &[AthalarAtom],
+    generator_name: &str,
+    project_source: &Path,
+) -> anyhow::Result<String> {
+    let profile = match &binding.profile {
+        AthalarAdapter::Template(x) => x,
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    let template_path = RelativePath::from_path(&profile.path)?.to_logical_path(project_source);
+    let template = fs::read_to_string(&template_path)
+        .map_err(|e| anyhow::anyhow!("Unable to read template {:?}: {}", template_path, e))?;
+    let context = Context {
+        generator: GeneratorContext {
+            name: generator_name.to_string(),
+        },
+        atoms: atoms.iter().map(AtomContext::from).collect(),
+        options: profile.options.clone(),
+    };
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = Tera::one_off(&template, &context, false)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the template profile.
+pub struct TemplateRenderer;
+
+impl FileRenderer for TemplateRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_template_contents(
+            binding,
+            atoms,
+            context.generator_name,
+            context.project_source,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, TemplateAdapterProfileBuilder,
+    };
+    use serde_yaml::Value;
+    use std::{env, path::PathBuf};
+
+    #[test]
+    fn renders_project_template_with_context() {
+        let project_source = env::temp_dir().join("athalar_template_test");
+        fs::create_dir_all(&project_source).unwrap();
+        fs::write(
+            project_source.join("shell.tera"),
+            "# {{ generator.name }} for {{ options.shell }}\n\
+             {% for atom in atoms %}export {{ atom.name }}=\"${{ '{' }}{{ atom.name }}:-{{ atom.default | default(value='') }}}\" # {{ atom.validators | join(sep=',') }} {{ atom.required }}\n{% endfor %}",
+        )
+        .unwrap();
+        let mut options = Mapping::new();
+        options.insert(Value::from("shell"), Value::from("bash"));
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("config.sh"))
+            .profile(AthalarAdapter::Template(
+                TemplateAdapterProfileBuilder::default()
+                    .path("shell.tera")
+                    .options(options)
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .default_value("25")
+            .build()
+            .unwrap()];
+        let contents = get_template_contents(&binding, &atoms, "backend", &project_source).unwrap();
+        assert_eq!(
+            contents,
+            "# backend for bash\nexport MAIL_PORT=\"${MAIL_PORT:-25}\" # Port false\n"
+        );
+    }
+
+    #[test]
+    fn missing_template_is_an_error() {
+        let binding = AthalarBindingBuilder::default()
+            .output(PathBuf::from("config.sh"))
+            .profile(AthalarAdapter::Template(
+                TemplateAdapterProfileBuilder::default()
+                    .path("does-not-exist.tera")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let result = get_template_contents(&binding, &[], "backend", &env::temp_dir());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use athalar_core::{AtomKind, AtomValidator};
+use strum_macros::Display;
+
+#[derive(Debug, Display)]
+pub enum AthalarZodKind {
+    // environment variables are always strings, so numbers need to be coerced
+    #[strum(serialize = "z.coerce.number()")]
+    Number,
+
+    #[strum(serialize = "z.string()")]
+    String,
+
+    #[strum(serialize = "z.any()")]
+    Any,
+}
+
+impl From<AtomKind> for AthalarZodKind {
+    fn from(av: AtomKind) -> Self {
+        match av {
+            AtomKind::Number => Self::Number,
+            AtomKind::String => Self::String,
+            AtomKind::Any => Self::Any,
+        }
+    }
+}
+
+/// The methods that are chained on the schema of an atom to enforce its validators.
+#[derive(Debug, Display)]
+pub enum AthalarZodRefinement {
+    #[strum(serialize = ".url()")]
+    Url,
+
+    #[strum(serialize = ".int().min(0).max(65535)")]
+    Port,
+}
+
+impl AthalarZodRefinement {
+    /// Returns the refinement for a validator, if it can be applied to the schema of the
+    /// given kind.
+    pub fn from_validator(av: &AtomValidator, kind: AtomKind) -> Option<Self> {
+        match (av, kind) {
+            (AtomValidator::Url, AtomKind::String) => Some(Self::Url),
+            (AtomValidator::Port, AtomKind::Number) => Some(Self::Port),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub const DEFAULT_SCHEMA_NAME: &str = "ConfigSchema";
+pub const DEFAULT_TYPE_NAME: &str = "Config";
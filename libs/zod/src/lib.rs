@@ -0,0 +1,173 @@
+mod atoms;
+mod constants;
+
+use crate::{
+    atoms::{AthalarZodKind, AthalarZodRefinement},
+    constants::{DEFAULT_SCHEMA_NAME, DEFAULT_TYPE_NAME},
+};
+use athalar_core::{
+    render_template, AthalarAdapter, AthalarAtom, AthalarBinding, FileRenderer, RenderContext,
+    UnsupportedProfileError,
+};
+use serde::{Deserialize, Serialize};
+use tera::Context as TeraContext;
+
+const ZOD_TEMPLATE: &str = include_str!("zod.tera");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PropertyContext {
+    name: String,
+    schema: String,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Context {
+    schema_name: String,
+    type_name: String,
+    properties: Vec<PropertyContext>,
+}
+
+pub fn get_zod_contents(binding: &AthalarBinding, atoms: &[AthalarAtom]) -> anyhow::Result<String> {
+    let mut context = match &binding.profile {
+        AthalarAdapter::Zod(x) => Context {
+            schema_name: x
+                .schema_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_SCHEMA_NAME.to_string()),
+            type_name: x
+                .type_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_TYPE_NAME.to_string()),
+            properties: vec![],
+        },
+        other => return Err(UnsupportedProfileError(other.into()).into()),
+    };
+    for atom in atoms {
+        let mut refinements = atom
+            .validators
+            .iter()
+            .filter_map(|v| AthalarZodRefinement::from_validator(v, atom.kind))
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>();
+        refinements.dedup();
+        // a variable with a default is always set, so it does not need to be optional
+        match atom.get_default_literal() {
+            Some(literal) => refinements.push(format!(".default({})", literal)),
+            None if atom.optional => refinements.push(".optional()".to_string()),
+            None => {}
+        }
+        context.properties.push(PropertyContext {
+            name: atom.name.clone(),
+            schema: format!(
+                "{}{}",
+                AthalarZodKind::from(atom.kind),
+                refinements.join("")
+            ),
+            comment: atom.description.clone(),
+        })
+    }
+    let context = TeraContext::from_serialize(context)?;
+    let rendered = render_template(ZOD_TEMPLATE, &context)?;
+    Ok(rendered)
+}
+
+/// Renders the bindings that use the zod profile.
+pub struct ZodRenderer;
+
+impl FileRenderer for ZodRenderer {
+    fn render_contents(
+        &self,
+        binding: &AthalarBinding,
+        atoms: &[AthalarAtom],
+        _context: &RenderContext,
+    ) -> anyhow::Result<String> {
+        get_zod_contents(binding, atoms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use athalar_core::{
+        AthalarAtomBuilder, AthalarBindingBuilder, AtomValidator, ZodAdapterProfileBuilder,
+    };
+    use std::path::PathBuf;
+
+    fn binding() -> AthalarBinding {
+        AthalarBindingBuilder::default()
+            .output(PathBuf::from("backend.ts"))
+            .profile(AthalarAdapter::Zod(
+                ZodAdapterProfileBuilder::default().build().unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn validators_are_chained_on_the_schema() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .description(Some("The mail port".to_string()))
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_zod_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains(
+            "   * The mail port\n   */\n  MAIL_PORT: z.coerce.number().int().min(0).max(65535),\n"
+        ));
+        assert!(contents.contains("  API_URL: z.string().url(),\n"));
+        assert!(contents.contains("export const ConfigSchema = z.object({"));
+        assert!(contents.contains("export type Config = z.infer<typeof ConfigSchema>;"));
+    }
+
+    #[test]
+    fn every_line_of_a_description_is_in_the_comment() {
+        let atoms = vec![AthalarAtomBuilder::default()
+            .name("MAIL_PORT")
+            .validators(vec![AtomValidator::Port])
+            .description(Some("The mail port\nused for alerts\n".to_string()))
+            .build()
+            .unwrap()];
+        let contents = get_zod_contents(&binding(), &atoms).unwrap();
+        assert!(contents.contains(
+            "  /**\n   * The mail port\n   * used for alerts\n   */\n  MAIL_PORT: z.coerce"
+        ));
+    }
+
+    #[test]
+    fn optional_atoms_and_defaults_can_be_left_unset() {
+        let atoms = vec![
+            AthalarAtomBuilder::default()
+                .name("MAIL_PORT")
+                .validators(vec![AtomValidator::Port])
+                .default_value("25")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("MAIL_HOST")
+                .validators(vec![AtomValidator::String])
+                .default_value("localhost")
+                .build()
+                .unwrap(),
+            AthalarAtomBuilder::default()
+                .name("API_URL")
+                .validators(vec![AtomValidator::Url])
+                .optional(true)
+                .build()
+                .unwrap(),
+        ];
+        let contents = get_zod_contents(&binding(), &atoms).unwrap();
+        assert!(contents
+            .contains("  MAIL_PORT: z.coerce.number().int().min(0).max(65535).default(25),\n"));
+        assert!(contents.contains("  MAIL_HOST: z.string().default(\"localhost\"),\n"));
+        assert!(contents.contains("  API_URL: z.string().url().optional(),\n"));
+    }
+}